@@ -5,41 +5,139 @@
 //! ### Connection types:
 //!
 //! * [`Client`] - Represents a connection to VLC's TCP interface.
+//! * [`ClientBuilder`] - Configures timeouts and socket options for a client's connection.
+//! * [`ReconnectingClient`] - A client that reconnects to VLC when its connection breaks.
+//! * [`VlcCommand`] - Describes a VLC player for a client to spawn and own.
+//! * [`Transport`] - A byte stream a client can use to talk to VLC.
+//! * `AsyncClient` - An asynchronous connection to VLC's interface over TCP or any tokio stream (requires the `tokio` feature).
 //!
 //! ### Media types:
 //!
 //! * [`Track`] - Represents a media track in a VLC player's playlist.
 //! * [`Playlist`] - A collection of tracks.
+//! * [`SortKey`] - A key to sort the playlist by.
+//! * [`SortOrder`] - The order to sort the playlist in.
+//! * [`PlaybackModes`] - The playback modes last set through a client.
+//! * [`PlaybackState`] - Whether the player is playing, paused or stopped.
+//! * [`Section`] - A title or chapter of the current input.
 //! * [`Subtitle`] - A subtitle track associated with a media file.
 //! * [`Subtitles`] - A collection of subtitle tracks.
+//! * [`AudioTrack`] - An audio track associated with a media file.
+//! * [`AudioTracks`] - A collection of audio tracks.
+//! * [`VideoTrack`] - A video track associated with a media file.
+//! * [`VideoTracks`] - A collection of video tracks.
+//! * [`AudioDevice`] - An audio output device VLC can play through.
+//! * [`AudioDevices`] - A collection of audio output devices.
+//! * [`AudioChannel`] - An audio channel mode, such as stereo or mono.
+//! * [`AudioChannels`] - A collection of audio channel modes.
+//! * [`MediaInfo`] - The current input's tags and elementary streams.
+//! * [`StreamInfo`] - An elementary stream's codec, language and format.
+//! * [`StreamKind`] - Whether a stream carries audio, video or subtitles.
+//! * [`PlaybackStats`] - The current input's bitrates and decoded, displayed and lost frames.
+//!
+//! ### Notification types:
+//!
+//! * [`StatusChange`] - A notification VLC sends when the player's state changes.
+//! * [`PlayerEvent`] - A change in the player's state, yielded by a [`Watcher`].
 //!
 //! When using the library, you'd typically construct a new [`Client`] and then proceed to issue commands by using the client's methods.
 
+#[cfg(feature = "tokio")]
+mod async_client;
+mod builder;
+mod framing;
+mod info;
 mod media;
+mod process;
+mod reconnect;
 mod socket;
-
+mod stats;
+mod uri;
+mod watch;
+
+#[cfg(feature = "tokio")]
+pub use async_client::AsyncClient;
+pub use builder::ClientBuilder;
+pub use info::MediaInfo;
+pub use info::StreamInfo;
+pub use info::StreamKind;
+pub use media::AudioChannel;
+pub use media::AudioChannels;
+pub use media::AudioDevice;
+pub use media::AudioDevices;
+pub use media::AudioTrack;
+pub use media::AudioTracks;
+pub use media::PlaybackModes;
+pub use media::PlaybackState;
 pub use media::Playlist;
+pub use media::Section;
+pub use media::SortKey;
+pub use media::SortOrder;
+pub use media::StatusChange;
 pub use media::Subtitle;
 pub use media::Subtitles;
 pub use media::Track;
+pub use media::VideoTrack;
+pub use media::VideoTracks;
+pub use media::MAX_RATE;
 pub use media::MAX_VOLUME;
+pub use media::MIN_RATE;
 pub use media::MIN_VOLUME;
-
-use std::io::prelude::*;
+pub use process::VlcCommand;
+pub use reconnect::Backoff;
+pub use reconnect::ReconnectingClient;
+pub use socket::Transport;
+pub use stats::PlaybackStats;
+pub use watch::PlayerEvent;
+pub use watch::Watcher;
+
+use std::ffi::OsStr;
 use std::net::ToSocketAddrs;
+use std::thread;
+use std::time::Duration;
 
+#[cfg(unix)]
+use std::path::Path;
+
+use crate::Error;
 use crate::Result;
 
 use media::FromParts;
+use media::Selectable;
+use socket::BufStream;
 use socket::IoSocket;
-use socket::PROMPT;
 
 /// A connection to a VLC player's TCP interface.
 pub struct Client {
     socket: IoSocket,
+    /// The last volume observed by the client.
+    volume: Option<u8>,
+    /// The last fullscreen mode set by the client.
+    fullscreen: Option<bool>,
+    /// The last playback modes set by the client.
+    modes: PlaybackModes,
 }
 
 impl Client {
+    /// The maximum amount of times the player is read while waiting for VLC to apply a command.
+    const RETRY_ATTEMPTS: usize = 10;
+
+    /// The amount of time to wait before reading the player again.
+    const RETRY_DELAY: Duration = Duration::from_millis(50);
+
+    /// VLC stores a rate as the integer `RATE_SCALE / rate`, and reports it back as `RATE_SCALE` divided by that integer.
+    const RATE_SCALE: f32 = 1000.0;
+
+    /// Creates a client that issues commands over the given socket.
+    fn from_socket(socket: IoSocket) -> Client {
+        Self {
+            socket,
+            volume: None,
+            fullscreen: None,
+            modes: PlaybackModes::default(),
+        }
+    }
+
     /// Establishes a connection to a VLC player's TCP interface at the given address.
     ///
     /// # Examples
@@ -53,36 +151,138 @@ impl Client {
     where
         A: ToSocketAddrs,
     {
-        Ok(Self { socket: IoSocket::connect(addr)? })
+        ClientBuilder::new().connect(addr)
     }
 
-    /// Gets a list of tracks in the VLC player's playlist.
+    /// Establishes a connection to a VLC player's Unix domain socket interface at the given path.
+    ///
+    /// VLC exposes this interface when it is launched with `--rc-unix <path>`.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use vlc_rc::Client;
     ///
-    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    /// let player = Client::connect_unix("/run/vlc.sock").unwrap();
+    /// ```
+    #[cfg(unix)]
+    pub fn connect_unix<P>(path: P) -> Result<Client>
+    where
+        P: AsRef<Path>,
+    {
+        ClientBuilder::new().connect_unix(path)
+    }
+
+    /// Establishes a connection to a VLC player's telnet interface at the given address, logging in with the given password.
     ///
-    /// let playlist = player.playlist().unwrap();
-    /// for track in playlist {
-    ///     println!("{}", track);
-    /// }
+    /// VLC exposes this interface when it is launched with `--intf telnet --telnet-password <password>` (on port `4212` by default).
+    ///
+    /// The telnet interface runs the same command line interface as the TCP one, so every method works over it. It also passes the commands it doesn't know on to VLM - issue those with [`Client::vlm`].
+    ///
+    /// Returns [`Error::Auth`] if VLC rejects the password.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect_telnet("127.0.0.1:4212", "secret").unwrap();
+    /// println!("{}", player.vlm("show").unwrap());
     /// ```
-    pub fn playlist(&mut self) -> Result<Playlist> {
-        writeln!(self.socket, "playlist")?;
-        self.socket.flush()?;
+    pub fn connect_telnet<A>(addr: A, password: &str) -> Result<Client>
+    where
+        A: ToSocketAddrs,
+    {
+        ClientBuilder::new().connect_telnet(addr, password)
+    }
 
-        let mut buf = Vec::new();
-        self.socket.read_until(PROMPT, &mut buf)?;
+    /// Launches a new VLC player and controls it over the process's stdin/stdout.
+    ///
+    /// The client owns the spawned process - it is killed and reaped when the client is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::VlcCommand;
+    /// use vlc_rc::Client;
+    ///
+    /// let cmd = VlcCommand::new().media("samples/audio.mp3");
+    /// let player = Client::spawn(cmd).unwrap();
+    /// ```
+    pub fn spawn(cmd: VlcCommand) -> Result<Client> {
+        Self::from_transport(BufStream::spawn(&mut cmd.to_command())?)
+    }
 
-        let out = String::from_utf8_lossy(&buf);
+    /// Starts a session with a VLC player over a custom [`Transport`].
+    ///
+    /// The transport should be positioned at the very start of VLC's output, since the greeting VLC gives a client when it connects is consumed before the client is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::io::BufReader;
+    /// use std::io::BufWriter;
+    /// use std::io::prelude::*;
+    /// use std::net::TcpStream;
+    ///
+    /// use vlc_rc::client::Transport;
+    /// use vlc_rc::Client;
+    ///
+    /// struct Tunnel {
+    ///     reader: BufReader<TcpStream>,
+    ///     writer: BufWriter<TcpStream>,
+    /// }
+    ///
+    /// # impl Read for Tunnel {
+    /// #     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> { self.reader.read(buf) }
+    /// # }
+    /// # impl BufRead for Tunnel {
+    /// #     fn fill_buf(&mut self) -> std::io::Result<&[u8]> { self.reader.fill_buf() }
+    /// #     fn consume(&mut self, amt: usize) { self.reader.consume(amt) }
+    /// # }
+    /// # impl Write for Tunnel {
+    /// #     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> { self.writer.write(buf) }
+    /// #     fn flush(&mut self) -> std::io::Result<()> { self.writer.flush() }
+    /// # }
+    /// impl Transport for Tunnel {}
+    ///
+    /// let stream = TcpStream::connect("127.0.0.1:9090").unwrap();
+    /// let tunnel = Tunnel {
+    ///     reader: BufReader::new(stream.try_clone().unwrap()),
+    ///     writer: BufWriter::new(stream),
+    /// };
+    ///
+    /// let player = Client::from_transport(tunnel).unwrap();
+    /// ```
+    pub fn from_transport<T>(transport: T) -> Result<Client>
+    where
+        T: Transport + 'static,
+    {
+        ClientBuilder::new().from_transport(transport)
+    }
 
-        Ok(out.lines().filter_map(Track::from_parts).collect())
+    /// Executes the given VLM command and returns its output.
+    ///
+    /// VLC's telnet interface passes the commands it doesn't know on to VLM - see [`Client::connect_telnet`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect_telnet("127.0.0.1:4212", "secret").unwrap();
+    ///
+    /// player.vlm("new radio broadcast enabled").unwrap();
+    /// player.vlm("setup radio input http://example.com/stream.mp3").unwrap();
+    /// player.vlm("control radio play").unwrap();
+    /// ```
+    pub fn vlm(&mut self, command: &str) -> Result<String> {
+        self.socket.execute(command)
     }
 
-    /// Gets a list of subtitle tracks for the current media file.
+    /// Removes and returns the status change notifications VLC has sent so far, oldest first.
+    ///
+    /// VLC interleaves these notifications with the output of other commands, so they're only received while the client is issuing commands. At most 256 of them are kept - the oldest ones are discarded first.
     ///
     /// # Examples
     ///
@@ -91,24 +291,45 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// let subtitles = player.subtitles().unwrap();
-    /// for strack in subtitles {
-    ///     println!("{}", strack);
+    /// player.set_volume(50).unwrap();
+    /// for change in player.status_changes() {
+    ///     println!("{}", change);
     /// }
     /// ```
-    pub fn subtitles(&mut self) -> Result<Subtitles> {
-        writeln!(self.socket, "strack")?;
-        self.socket.flush()?;
-
-        let mut buf = Vec::new();
-        self.socket.read_until(PROMPT, &mut buf)?;
-
-        let out = String::from_utf8_lossy(&buf);
+    pub fn status_changes(
+        &mut self,
+    ) -> impl Iterator<Item = StatusChange> + '_ {
+        self.socket.status_changes()
+    }
 
-        Ok(out.lines().filter_map(Subtitle::from_parts).collect())
+    /// Watches the VLC player for changes by polling its state on the given interval.
+    ///
+    /// See [`Watcher`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use vlc_rc::client::PlayerEvent;
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// for event in player.watch(Duration::from_millis(500)) {
+    ///     match event.unwrap() {
+    ///         PlayerEvent::TrackChanged(Some(title)) => println!("now playing '{}'", title),
+    ///         PlayerEvent::Stopped => break,
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn watch(&mut self, interval: Duration) -> Watcher<'_> {
+        Watcher::new(self, interval)
     }
 
-    /// Gets the VLC player's current volume.
+    /// Gets a list of tracks in the VLC player's playlist.
+    ///
     /// # Examples
     ///
     /// ```
@@ -116,28 +337,22 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// let volume = player.get_volume().unwrap();
-    /// println!("the current volume is {}", volume);
+    /// let playlist = player.playlist().unwrap();
+    /// for track in playlist {
+    ///     println!("{}", track);
+    /// }
     /// ```
-    pub fn get_volume(&mut self) -> Result<u8> {
-        writeln!(self.socket, "volume")?;
-        self.socket.flush()?;
-
-        let mut line = String::new();
-        self.socket.read_line(&mut line)?;
-
-        let volume = line.trim().parse::<u16>()?;
+    pub fn playlist(&mut self) -> Result<Playlist> {
+        let out = self.socket.execute("playlist")?;
 
-        if volume <= (MAX_VOLUME as u16) {
-            Ok(volume as u8)
-        } else {
-            Ok(MAX_VOLUME)
-        }
+        Ok(out.lines().filter_map(Track::from_parts).collect())
     }
 
-    /// Sets the VLC player's volume to the given amount.
+    /// Adds the given media to the playlist and starts playing it.
     ///
-    /// If `amt` is greater than [`MAX_VOLUME`], it defaults to the max volume.
+    /// The media can either be a URI, or a path to a local file - which is converted to a `file://` URI.
+    ///
+    /// Returns the new track as it appears in the playlist, or [`Error::CommandIgnored`] if it doesn't show up.
     ///
     /// # Examples
     ///
@@ -146,51 +361,42 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.set_volume(50).unwrap();
-    /// assert_eq!(player.get_volume().unwrap(), 50);
+    /// let track = player.add("samples/audio.mp3").unwrap();
+    /// println!("now playing {}", track);
     /// ```
-    pub fn set_volume(&mut self, mut amt: u8) -> Result<()> {
-        if amt > MAX_VOLUME {
-            amt = MAX_VOLUME;
-        }
-
-        // Spam the interface until we get the desired output.
-        while self.get_volume()? != amt {
-            writeln!(self.socket, "volume {}", amt)?;
-            self.socket.flush()?;
-        }
-        Ok(())
+    pub fn add<S>(&mut self, media: S) -> Result<Track>
+    where
+        S: AsRef<OsStr>,
+    {
+        self.insert("add", media.as_ref())
     }
 
-    /// Returns whether or not the current media track is playing.
+    /// Adds the given media to the end of the playlist without playing it.
+    ///
+    /// The media can either be a URI, or a path to a local file - which is converted to a `file://` URI.
     ///
-    /// Note that if the track is paused, the method still returns `true`.
+    /// Returns the new track as it appears in the playlist, or [`Error::CommandIgnored`] if it doesn't show up.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use vlc_rc::Client;
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// let is_playing = player.is_playing().unwrap();
-    /// if is_playing {
-    ///     println!("the track is currently playing!");
-    /// } else {
-    ///     println!("the track is currently stopped!");
-    /// }
+    /// let track = player.enqueue("https://example.com/stream.mp3").unwrap();
+    /// println!("queued {}", track);
     /// ```
-    pub fn is_playing(&mut self) -> Result<bool> {
-        writeln!(self.socket, "is_playing")?;
-        self.socket.flush()?;
-
-        let mut line = String::new();
-        self.socket.read_line(&mut line)?;
-
-        Ok(line.trim() == "1")
+    pub fn enqueue<S>(&mut self, media: S) -> Result<Track>
+    where
+        S: AsRef<OsStr>,
+    {
+        self.insert("enqueue", media.as_ref())
     }
 
-    /// Plays the current media track.
+    /// Removes the track with the given [index](Track::index) from the playlist.
+    ///
+    /// Returns [`Error::CommandIgnored`] if the track is still in the playlist afterwards.
     ///
     /// # Examples
     ///
@@ -199,22 +405,20 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.play().unwrap();
-    /// assert_eq!(player.is_playing().unwrap(), true);
+    /// let track = player.enqueue("samples/audio.mp3").unwrap();
+    /// player.delete(track.index()).unwrap();
     /// ```
-    pub fn play(&mut self) -> Result<()> {
-        // Only issue the 'play' command if the playlist is not empty.
-        if !self.playlist()?.is_empty() {
-            // Spam the interface until we get the desired output.
-            while !self.is_playing()? {
-                writeln!(self.socket, "play")?;
-                self.socket.flush()?;
-            }
-        }
-        Ok(())
+    pub fn delete(&mut self, index: i32) -> Result<()> {
+        self.socket.execute(&format!("delete {}", index))?;
+
+        self.wait_for_playlist(|playlist| {
+            playlist.iter().all(|t| t.index() != index).then_some(())
+        })
     }
 
-    /// Stops the current media track's playback.
+    /// Moves the track with the index `from` so that it comes right after the track with the index `to`.
+    ///
+    /// Returns [`Error::CommandIgnored`] if the track doesn't end up in its new position.
     ///
     /// # Examples
     ///
@@ -223,21 +427,26 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.stop().unwrap();
-    /// assert_eq!(player.is_playing().unwrap(), false);
+    /// let first = player.enqueue("samples/audio.mp3").unwrap();
+    /// let second = player.enqueue("samples/audio.mp3").unwrap();
+    ///
+    /// // Swap the two tracks.
+    /// player.move_item(first.index(), second.index()).unwrap();
     /// ```
-    pub fn stop(&mut self) -> Result<()> {
-        // Spam the interface until we get the desired output.
-        while self.is_playing()? {
-            writeln!(self.socket, "stop")?;
-            self.socket.flush()?;
-        }
-        Ok(())
+    pub fn move_item(&mut self, from: i32, to: i32) -> Result<()> {
+        self.socket.execute(&format!("move {} {}", from, to))?;
+
+        self.wait_for_playlist(|playlist| {
+            playlist
+                .windows(2)
+                .any(|w| w[0].index() == to && w[1].index() == from)
+                .then_some(())
+        })
     }
 
-    /// Pauses the current track's playback.
+    /// Starts playing the track with the given [index](Track::index).
     ///
-    /// Does nothing if the track is stopped.
+    /// Returns [`Error::CommandIgnored`] if the track doesn't become the playlist's current track.
     ///
     /// # Examples
     ///
@@ -246,61 +455,114 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.pause().unwrap();
+    /// let track = player.enqueue("samples/audio.mp3").unwrap();
+    /// player.goto(track.index()).unwrap();
     /// ```
-    pub fn pause(&mut self) -> Result<()> {
-        if self.is_playing()? {
-            // The 'pause' command works as a toggle, so we need to ensure that the track is playing before we execute it to get the desired behavior.
-            writeln!(self.socket, "play")?;
-            writeln!(self.socket, "pause")?;
-            self.socket.flush()?;
-        }
-        Ok(())
+    pub fn goto(&mut self, index: i32) -> Result<()> {
+        self.socket.execute(&format!("goto {}", index))?;
+
+        self.wait_for_playlist(|playlist| {
+            playlist
+                .iter()
+                .any(|t| t.index() == index && t.is_current())
+                .then_some(())
+        })
     }
 
-    /// Gets the elapsed time since the track's beginning (in seconds).
+    /// Removes every track from the playlist.
     ///
-    /// Returns `None` if the current track is stopped.
+    /// Returns [`Error::CommandIgnored`] if the playlist isn't empty afterwards.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use vlc_rc::Client;
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// let seconds = player.get_time().unwrap();
+    /// player.clear().unwrap();
     /// ```
-    pub fn get_time(&mut self) -> Result<Option<u32>> {
-        writeln!(self.socket, "get_time")?;
-        self.socket.flush()?;
-
-        let mut line = String::new();
-        self.socket.read_line(&mut line)?;
+    pub fn clear(&mut self) -> Result<()> {
+        self.socket.execute("clear")?;
 
-        Ok(line.trim().parse().ok())
+        // VLC only clears the `Playlist` node, the `Media Library` keeps its tracks.
+        self.wait_until(|client| {
+            Ok(client.playlist_node()?.is_empty().then_some(()))
+        })
     }
 
-    /// Moves the track's playback to the given position (in seconds).
+    /// Sorts the playlist by the given key, in the given order.
+    ///
+    /// VLC only sorts in ascending order by itself, so a descending sort is done by reversing the sorted playlist one track at a time - that takes a `move` command per track, which adds up for long playlists. Only the tracks under the `Playlist` node are reversed, the `Media Library` is left alone. The order is ignored when shuffling with [`SortKey::Random`].
     ///
     /// # Examples
     ///
     /// ```
+    /// use vlc_rc::client::SortKey;
+    /// use vlc_rc::client::SortOrder;
     /// use vlc_rc::Client;
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// // Move playback to the 60 seconds position
-    /// player.seek(60).unwrap()
+    /// player.sort(SortKey::Title, SortOrder::Descending).unwrap();
     /// ```
-    pub fn seek(&mut self, secs: u32) -> Result<()> {
-        writeln!(self.socket, "seek {}", secs)?;
-        self.socket.flush()?;
+    pub fn sort(&mut self, key: SortKey, order: SortOrder) -> Result<()> {
+        // VLC explains why it didn't sort the playlist, but stays silent otherwise.
+        if !self.socket.execute(&format!("sort {}", key))?.is_empty() {
+            return Err(Error::CommandIgnored);
+        }
 
+        if order == SortOrder::Descending && key != SortKey::Random {
+            let playlist = self.playlist_node()?;
+            if let Some((last, rest)) = playlist.split_last() {
+                // Moving every other track after the last one, in order, reverses the playlist.
+                for track in rest {
+                    self.socket.execute(&format!(
+                        "move {} {}",
+                        track.index(),
+                        last.index()
+                    ))?;
+                }
+
+                let reversed: Vec<_> =
+                    playlist.iter().rev().map(Track::index).collect();
+                self.wait_until(|client| {
+                    let playlist = client.playlist_node()?;
+                    Ok(playlist
+                        .iter()
+                        .map(Track::index)
+                        .eq(reversed.iter().copied())
+                        .then_some(()))
+                })?;
+            }
+        }
         Ok(())
     }
 
-    /// Moves the track's playback forward by the given amount (in seconds).
+    /// Gets the tracks under the playlist's `Playlist` node, leaving out those in the `Media Library`.
+    fn playlist_node(&mut self) -> Result<Playlist> {
+        let out = self.socket.execute("playlist")?;
+
+        // The root nodes are the only unindented rows, and the `Playlist` node always comes first.
+        let mut roots = 0;
+        Ok(out
+            .lines()
+            .take_while(|line| {
+                let is_root = line
+                    .strip_prefix("| ")
+                    .is_some_and(|row| !row.starts_with(' '));
+                if is_root {
+                    roots += 1;
+                }
+                roots < 2
+            })
+            .filter_map(Track::from_parts)
+            .collect())
+    }
+
+    /// Filters the playlist down to the tracks matching the given query, and returns them.
+    ///
+    /// The filter stays in place for subsequent calls to [`Client::playlist`] until it's removed with [`Client::reset_search`].
     ///
     /// # Examples
     ///
@@ -309,16 +571,17 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.forward(5).unwrap();
+    /// for track in player.search("Chopin").unwrap() {
+    ///     println!("{}", track);
+    /// }
+    /// player.reset_search().unwrap();
     /// ```
-    pub fn forward(&mut self, secs: u32) -> Result<()> {
-        writeln!(self.socket, "seek +{}", secs)?;
-        self.socket.flush()?;
-
-        Ok(())
+    pub fn search(&mut self, query: &str) -> Result<Playlist> {
+        self.socket.execute(&format!("search {}", query))?;
+        self.playlist()
     }
 
-    /// Moves the track's playback backward by the given amount (in seconds).
+    /// Removes the filter set by [`Client::search`], so that the playlist shows every track again.
     ///
     /// # Examples
     ///
@@ -327,18 +590,64 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.rewind(5).unwrap();
+    /// player.reset_search().unwrap();
     /// ```
-    pub fn rewind(&mut self, secs: u32) -> Result<()> {
-        writeln!(self.socket, "seek -{}", secs)?;
-        self.socket.flush()?;
-
+    pub fn reset_search(&mut self) -> Result<()> {
+        self.socket.execute("search")?;
         Ok(())
     }
 
-    /// Gets the current media track's title.
+    /// Issues a command that inserts the given media into the playlist, and returns the track it created.
+    fn insert(&mut self, cmd: &str, media: &OsStr) -> Result<Track> {
+        let uri = uri::to_uri(media)?;
+
+        let before = self.playlist()?;
+        self.socket.execute(&format!("{} {}", cmd, uri))?;
+
+        self.wait_for_playlist(|playlist| {
+            playlist
+                .iter()
+                .find(|t| before.iter().all(|b| b.index() != t.index()))
+                .cloned()
+        })
+    }
+
+    /// Calls the given function until it finds what it's looking for, giving VLC some time to apply a command.
     ///
-    /// Returns `None` if the media player is stopped.
+    /// Returns [`Error::CommandIgnored`] if it's never found.
+    fn wait_until<T, F>(&mut self, mut f: F) -> Result<T>
+    where
+        F: FnMut(&mut Client) -> Result<Option<T>>,
+    {
+        for attempt in 0..Self::RETRY_ATTEMPTS {
+            if attempt > 0 {
+                thread::sleep(Self::RETRY_DELAY);
+            }
+            if let Some(found) = f(self)? {
+                return Ok(found);
+            }
+        }
+        Err(Error::CommandIgnored)
+    }
+
+    /// Reads the playlist until the given function finds what it's looking for, giving VLC some time to apply a command.
+    ///
+    /// Returns [`Error::CommandIgnored`] if it's never found.
+    fn wait_for_playlist<T, F>(&mut self, mut f: F) -> Result<T>
+    where
+        F: FnMut(&Playlist) -> Option<T>,
+    {
+        self.wait_until(|client| Ok(f(&client.playlist()?)))
+    }
+
+    /// Reads the playback state until it's the given one, giving VLC some time to apply a command.
+    ///
+    /// Returns [`Error::CommandIgnored`] if it never is.
+    fn wait_for_state(&mut self, state: PlaybackState) -> Result<()> {
+        self.wait_until(|client| Ok((client.state()? == state).then_some(())))
+    }
+
+    /// Turns repeating the current track on/off.
     ///
     /// # Examples
     ///
@@ -347,27 +656,15 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// let current_track = player.get_title().unwrap();
-    /// if let Some(title) = current_track {
-    ///     println!("the track '{}' is currently playing!", title);
-    /// }
+    /// player.set_repeat(false).unwrap();
     /// ```
-    pub fn get_title(&mut self) -> Result<Option<String>> {
-        writeln!(self.socket, "get_title")?;
-        self.socket.flush()?;
-
-        let mut line = String::new();
-        self.socket.read_line(&mut line)?;
-
-        // If the line is empty, it means that the player is currently stopped - so we can just return `None`.
-        if line.trim().len() != 0 {
-            Ok(Some(line.trim().to_owned()))
-        } else {
-            Ok(None)
-        }
+    pub fn set_repeat(&mut self, on: bool) -> Result<()> {
+        self.set_mode("repeat", on)?;
+        self.modes.repeat = Some(on);
+        Ok(())
     }
 
-    /// Plays the next track in the playlist.
+    /// Turns looping the playlist on/off.
     ///
     /// # Examples
     ///
@@ -376,16 +673,15 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.next().unwrap();
+    /// player.set_loop(false).unwrap();
     /// ```
-    pub fn next(&mut self) -> Result<()> {
-        writeln!(self.socket, "next")?;
-        self.socket.flush()?;
-
+    pub fn set_loop(&mut self, on: bool) -> Result<()> {
+        self.set_mode("loop", on)?;
+        self.modes.looping = Some(on);
         Ok(())
     }
 
-    /// Plays the previous track in the playlist.
+    /// Turns playing the playlist in a random order on/off.
     ///
     /// # Examples
     ///
@@ -394,50 +690,1231 @@ impl Client {
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.prev().unwrap();
+    /// player.set_random(false).unwrap();
     /// ```
-    pub fn prev(&mut self) -> Result<()> {
-        writeln!(self.socket, "prev")?;
-        self.socket.flush()?;
+    pub fn set_random(&mut self, on: bool) -> Result<()> {
+        self.set_mode("random", on)?;
+        self.modes.random = Some(on);
+        Ok(())
+    }
 
+    /// Sets the playback mode toggled by the given command.
+    fn set_mode(&mut self, cmd: &str, on: bool) -> Result<()> {
+        // Passing the value explicitly keeps a repeated call from toggling the mode back off.
+        self.socket.execute(&format!(
+            "{} {}",
+            cmd,
+            if on { "on" } else { "off" }
+        ))?;
         Ok(())
     }
 
-    /// Toggles the media player's fullscreen mode on/off.
+    /// Gets the playback modes last set through this client.
     ///
-    ///  # Examples
+    /// See [`PlaybackModes`] for why the modes aren't read from the player.
+    ///
+    /// # Examples
     ///
     /// ```
     /// use vlc_rc::Client;
     ///
     /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
     ///
-    /// player.fullscreen(true).unwrap();
-    /// println!("fullscreen is on!");
+    /// player.set_loop(true).unwrap();
+    /// assert_eq!(player.playback_modes().looping(), Some(true));
+    /// ```
+    pub fn playback_modes(&self) -> PlaybackModes {
+        self.modes
+    }
+
+    /// Gets a list of subtitle tracks for the current media file.
+    ///
+    /// # Examples
     ///
-    /// player.fullscreen(false).unwrap();
-    /// println!("fullscreen is off!");
     /// ```
-    pub fn fullscreen(&mut self, on: bool) -> Result<()> {
-        writeln!(self.socket, "fullscreen {}", if on { "on" } else { "off" })?;
-        self.socket.flush()?;
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let subtitles = player.subtitles().unwrap();
+    /// for strack in subtitles {
+    ///     println!("{}", strack);
+    /// }
+    /// ```
+    pub fn subtitles(&mut self) -> Result<Subtitles> {
+        let out = self.socket.execute("strack")?;
 
-        Ok(())
+        Ok(out.lines().filter_map(Subtitle::from_parts).collect())
     }
-}
 
-impl Drop for Client {
-    fn drop(&mut self) {
-        if let Ok(_) = self.socket.shutdown() {}
+    /// Gets the subtitle track that is currently shown.
+    ///
+    /// Returns `None` if subtitles are disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// if let Some(strack) = player.current_subtitle().unwrap() {
+    ///     println!("showing {}", strack.title());
+    /// }
+    /// ```
+    pub fn current_subtitle(&mut self) -> Result<Option<Subtitle>> {
+        Ok(self
+            .subtitles()?
+            .into_iter()
+            .find(|s| s.is_active() && s.index() != -1))
+    }
+
+    /// Switches to the subtitle track with the given index, or disables subtitles if the index is `-1`.
+    ///
+    /// Returns [`Error::OutOfRange`] if the current media file has no such subtitle track.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.set_subtitle(2).unwrap();
+    /// assert_eq!(player.current_subtitle().unwrap().unwrap().index(), 2);
+    /// ```
+    pub fn set_subtitle(&mut self, index: i32) -> Result<()> {
+        self.select::<Subtitle>("strack", index)?;
+        Ok(())
+    }
+
+    /// Hides the subtitles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.disable_subtitles().unwrap();
+    /// assert!(player.current_subtitle().unwrap().is_none());
+    /// ```
+    pub fn disable_subtitles(&mut self) -> Result<()> {
+        self.set_subtitle(-1)
+    }
+
+    /// Gets a list of audio tracks for the current media file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let audio_tracks = player.audio_tracks().unwrap();
+    /// for atrack in audio_tracks {
+    ///     println!("{}", atrack);
+    /// }
+    /// ```
+    pub fn audio_tracks(&mut self) -> Result<AudioTracks> {
+        let out = self.socket.execute("atrack")?;
+
+        Ok(out.lines().filter_map(AudioTrack::from_parts).collect())
+    }
+
+    /// Switches to the audio track with the given index, or disables audio if the index is `-1`.
+    ///
+    /// Returns [`Error::OutOfRange`] if the current media file has no such audio track.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let atrack = player.audio_tracks().unwrap().last().unwrap().index();
+    /// player.set_audio_track(atrack).unwrap();
+    /// ```
+    pub fn set_audio_track(&mut self, index: i32) -> Result<()> {
+        self.select::<AudioTrack>("atrack", index)?;
+        Ok(())
+    }
+
+    /// Gets a list of video tracks for the current media file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let video_tracks = player.video_tracks().unwrap();
+    /// for vtrack in video_tracks {
+    ///     println!("{}", vtrack);
+    /// }
+    /// ```
+    pub fn video_tracks(&mut self) -> Result<VideoTracks> {
+        let out = self.socket.execute("vtrack")?;
+
+        Ok(out.lines().filter_map(VideoTrack::from_parts).collect())
+    }
+
+    /// Switches to the video track with the given index, or disables video if the index is `-1`.
+    ///
+    /// Returns [`Error::OutOfRange`] if the current media file has no such video track.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// // Keep playing the audio only.
+    /// player.set_video_track(-1).unwrap();
+    /// ```
+    pub fn set_video_track(&mut self, index: i32) -> Result<()> {
+        self.select::<VideoTrack>("vtrack", index)?;
+        Ok(())
+    }
+
+    /// Gets a list of the audio output devices VLC can play through.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let devices = player.audio_devices().unwrap();
+    /// for adev in devices {
+    ///     println!("{}", adev);
+    /// }
+    /// ```
+    pub fn audio_devices(&mut self) -> Result<AudioDevices> {
+        let out = self.socket.execute("adev")?;
+
+        Ok(out.lines().filter_map(AudioDevice::from_parts).collect())
+    }
+
+    /// Switches the audio output to the device with the given identifier, and returns the devices as VLC lists them afterwards.
+    ///
+    /// Returns [`Error::InvalidCommand`] if the identifier is empty - VLC would list the devices instead of switching to one, so the default device can't be switched back to. Returns [`Error::OutOfRange`] if VLC doesn't list such a device.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let devices = player
+    ///     .set_audio_device("alsa_output.usb-headset.analog-stereo")
+    ///     .unwrap();
+    /// assert!(devices.iter().any(|adev| adev.is_active()));
+    /// ```
+    pub fn set_audio_device(&mut self, id: &str) -> Result<AudioDevices> {
+        if id.trim().is_empty() {
+            return Err(Error::InvalidCommand);
+        }
+        self.select::<AudioDevice>("adev", id.to_owned())
+    }
+
+    /// Gets a list of the audio channel modes VLC can play the current media file with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let channels = player.audio_channels().unwrap();
+    /// for achan in channels {
+    ///     println!("{}", achan);
+    /// }
+    /// ```
+    pub fn audio_channels(&mut self) -> Result<AudioChannels> {
+        let out = self.socket.execute("achan")?;
+
+        Ok(out.lines().filter_map(AudioChannel::from_parts).collect())
+    }
+
+    /// Switches to the audio channel mode with the given index, and returns the channel modes as VLC lists them afterwards.
+    ///
+    /// Returns [`Error::OutOfRange`] if VLC doesn't list such a channel mode.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let mono = player
+    ///     .audio_channels()
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .find(|achan| achan.title() == "Mono")
+    ///     .unwrap();
+    /// let channels = player.set_audio_channel(mono.index()).unwrap();
+    /// assert!(channels.iter().any(|achan| achan.is_active()));
+    /// ```
+    pub fn set_audio_channel(&mut self, index: i32) -> Result<AudioChannels> {
+        self.select::<AudioChannel>("achan", index)
+    }
+
+    /// Selects the item with the given key from the listing the given command outputs, waits for VLC to report it as the active one, and returns the listing that did.
+    ///
+    /// Returns [`Error::OutOfRange`] if the listing has no such item.
+    fn select<T>(&mut self, cmd: &str, key: T::Key) -> Result<Vec<T>>
+    where
+        T: FromParts + Selectable,
+    {
+        let items = |client: &mut Client| -> Result<Vec<T>> {
+            let out = client.socket.execute(cmd)?;
+            Ok(out.lines().filter_map(T::from_parts).collect())
+        };
+
+        if !items(self)?.iter().any(|item| item.key() == key) {
+            return Err(Error::OutOfRange);
+        }
+
+        self.socket.execute(&format!("{} {}", cmd, key))?;
+        self.wait_until(|client| {
+            let items = items(client)?;
+            let active =
+                items.iter().any(|item| item.key() == key && item.is_active());
+            Ok(active.then_some(items))
+        })
+    }
+
+    /// Gets the current input's tags and elementary streams.
+    ///
+    /// Returns `None` if the media player is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// if let Some(info) = player.info().unwrap() {
+    ///     for stream in info.streams() {
+    ///         println!("{:?}: {:?}", stream.kind(), stream.codec());
+    ///     }
+    /// }
+    /// ```
+    pub fn info(&mut self) -> Result<Option<MediaInfo>> {
+        let out = self.socket.execute("info")?;
+
+        // VLC doesn't output anything if there's no input.
+        if out.trim().is_empty() {
+            return Ok(None);
+        }
+        MediaInfo::from_parts(&out).map(Some).ok_or(Error::ParseErr)
+    }
+
+    /// Gets the current input's playback statistics.
+    ///
+    /// Returns `None` if the media player is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// if let Some(stats) = player.stats().unwrap() {
+    ///     println!("{} frames lost", stats.frames_lost());
+    /// }
+    /// ```
+    pub fn stats(&mut self) -> Result<Option<PlaybackStats>> {
+        let out = self.socket.execute("stats")?;
+
+        // VLC doesn't output anything if there's no input.
+        if out.trim().is_empty() {
+            return Ok(None);
+        }
+        PlaybackStats::from_parts(&out).map(Some).ok_or(Error::ParseErr)
+    }
+
+    /// Gets the VLC player's current volume.
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let volume = player.get_volume().unwrap();
+    /// println!("the current volume is {}", volume);
+    /// ```
+    pub fn get_volume(&mut self) -> Result<u8> {
+        let line = self.socket.execute("volume")?;
+
+        let volume = line.trim().parse::<u16>()?;
+
+        let volume = if volume <= (MAX_VOLUME as u16) {
+            volume as u8
+        } else {
+            MAX_VOLUME
+        };
+
+        self.volume = Some(volume);
+        Ok(volume)
+    }
+
+    /// Sets the VLC player's volume to the given amount.
+    ///
+    /// If `amt` is greater than [`MAX_VOLUME`], it defaults to the max volume.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.set_volume(50).unwrap();
+    /// assert_eq!(player.get_volume().unwrap(), 50);
+    /// ```
+    pub fn set_volume(&mut self, mut amt: u8) -> Result<()> {
+        if amt > MAX_VOLUME {
+            amt = MAX_VOLUME;
+        }
+
+        // Spam the interface until we get the desired output.
+        while self.get_volume()? != amt {
+            self.socket.execute(&format!("volume {}", amt))?;
+        }
+        Ok(())
+    }
+
+    /// Gets the current track's playback rate, where `1.0` is the normal speed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let rate = player.get_rate().unwrap();
+    /// println!("playing at {}x", rate);
+    /// ```
+    pub fn get_rate(&mut self) -> Result<f32> {
+        let line = self.socket.execute("rate")?;
+
+        Ok(line.trim().parse()?)
+    }
+
+    /// Sets the current track's playback rate, where `1.0` is the normal speed.
+    ///
+    /// VLC can't represent most rates exactly, so the rate it plays at (and reports) can differ slightly from `rate`.
+    ///
+    /// Returns [`Error::OutOfRange`] if `rate` isn't between [`MIN_RATE`] and [`MAX_RATE`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.set_rate(1.5).unwrap();
+    /// let rate = player.get_rate().unwrap();
+    /// assert!((rate - 1.5).abs() < 0.01);
+    /// player.set_rate(1.0).unwrap();
+    /// ```
+    pub fn set_rate(&mut self, rate: f32) -> Result<()> {
+        if !(MIN_RATE..=MAX_RATE).contains(&rate) {
+            return Err(Error::OutOfRange);
+        }
+
+        self.socket.execute(&format!("rate {}", rate))?;
+        self.wait_for_rate(rate)
+    }
+
+    /// Reads the playback rate until it matches the given one, giving VLC some time to apply a command.
+    ///
+    /// Returns [`Error::CommandIgnored`] if it never does.
+    fn wait_for_rate(&mut self, rate: f32) -> Result<()> {
+        // Compare the rates the way VLC stores them, since it can't represent most rates exactly.
+        let requested = (Self::RATE_SCALE / rate) as i32;
+        self.wait_until(|client| {
+            let reported =
+                (Self::RATE_SCALE / client.get_rate()?).round() as i32;
+            Ok((reported == requested).then_some(()))
+        })
+    }
+
+    /// Issues a command that steps the playback rate to one of VLC's preset rates, and returns the new rate once VLC reports it.
+    ///
+    /// Returns [`Error::CommandIgnored`] if the rate never changes.
+    fn step_rate(&mut self, cmd: &str) -> Result<f32> {
+        let before = self.get_rate()?;
+        self.socket.execute(cmd)?;
+
+        self.wait_until(|client| {
+            let rate = client.get_rate()?;
+            Ok((rate != before).then_some(rate))
+        })
+    }
+
+    /// Speeds up the current track's playback to VLC's next preset rate, and returns the new rate.
+    ///
+    /// Returns [`Error::CommandIgnored`] if the rate doesn't change, e.g. because it's already the fastest preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let rate = player.faster().unwrap();
+    /// println!("playing at {}x", rate);
+    /// player.normal().unwrap();
+    /// ```
+    pub fn faster(&mut self) -> Result<f32> {
+        self.step_rate("faster")
+    }
+
+    /// Slows down the current track's playback to VLC's previous preset rate, and returns the new rate.
+    ///
+    /// Returns [`Error::CommandIgnored`] if the rate doesn't change, e.g. because it's already the slowest preset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let rate = player.slower().unwrap();
+    /// println!("playing at {}x", rate);
+    /// player.normal().unwrap();
+    /// ```
+    pub fn slower(&mut self) -> Result<f32> {
+        self.step_rate("slower")
+    }
+
+    /// Resets the current track's playback to the normal speed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.normal().unwrap();
+    /// assert_eq!(player.get_rate().unwrap(), 1.0);
+    /// ```
+    pub fn normal(&mut self) -> Result<()> {
+        self.socket.execute("normal")?;
+
+        self.wait_for_rate(1.0)
+    }
+
+    /// Returns whether or not the current media track is playing.
+    ///
+    /// Note that if the track is paused, the method still returns `true` - use [`Client::state`] to tell the two apart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let is_playing = player.is_playing().unwrap();
+    /// if is_playing {
+    ///     println!("the track is currently playing!");
+    /// } else {
+    ///     println!("the track is currently stopped!");
+    /// }
+    /// ```
+    pub fn is_playing(&mut self) -> Result<bool> {
+        let line = self.socket.execute("is_playing")?;
+
+        Ok(line.trim() == "1")
+    }
+
+    /// Gets whether the player is playing, paused or stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::client::PlaybackState;
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// if player.state().unwrap() == PlaybackState::Paused {
+    ///     println!("the track is currently paused!");
+    /// }
+    /// ```
+    pub fn state(&mut self) -> Result<PlaybackState> {
+        let out = self.socket.status()?;
+
+        // VLC doesn't report a state when there's no input, which is the same as being stopped.
+        Ok(out
+            .lines()
+            .rev()
+            .find_map(PlaybackState::from_parts)
+            .unwrap_or(PlaybackState::Stopped))
+    }
+
+    /// Plays the current media track, resuming it if it's paused.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::client::PlaybackState;
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.play().unwrap();
+    /// assert_eq!(player.state().unwrap(), PlaybackState::Playing);
+    /// ```
+    pub fn play(&mut self) -> Result<()> {
+        // Only issue the 'play' command if the playlist is not empty.
+        if !self.playlist()?.is_empty()
+            && self.state()? != PlaybackState::Playing
+        {
+            self.socket.execute("play")?;
+            self.wait_for_state(PlaybackState::Playing)?;
+        }
+        Ok(())
+    }
+
+    /// Stops the current media track's playback.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::client::PlaybackState;
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.stop().unwrap();
+    /// assert_eq!(player.state().unwrap(), PlaybackState::Stopped);
+    /// ```
+    pub fn stop(&mut self) -> Result<()> {
+        if self.state()? != PlaybackState::Stopped {
+            self.socket.execute("stop")?;
+            self.wait_for_state(PlaybackState::Stopped)?;
+        }
+        Ok(())
+    }
+
+    /// Pauses the current track's playback.
+    ///
+    /// Does nothing if the track is already paused or stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::client::PlaybackState;
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.play().unwrap();
+    /// player.pause().unwrap();
+    /// player.pause().unwrap();
+    /// assert_eq!(player.state().unwrap(), PlaybackState::Paused);
+    /// ```
+    pub fn pause(&mut self) -> Result<()> {
+        // The 'pause' command works as a toggle, so it's only issued when it's known to pause the track.
+        if self.state()? == PlaybackState::Playing {
+            self.socket.execute("pause")?;
+            self.wait_for_state(PlaybackState::Paused)?;
+        }
+        Ok(())
+    }
+
+    /// Resumes the current track's playback if it's paused.
+    ///
+    /// Does nothing if the track is playing or stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::client::PlaybackState;
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.pause().unwrap();
+    /// player.resume().unwrap();
+    /// ```
+    pub fn resume(&mut self) -> Result<()> {
+        if self.state()? == PlaybackState::Paused {
+            self.socket.execute("pause")?;
+            self.wait_for_state(PlaybackState::Playing)?;
+        }
+        Ok(())
+    }
+
+    /// Gets the elapsed time since the track's beginning (in seconds).
+    ///
+    /// Returns `None` if the current track is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let seconds = player.get_time().unwrap();
+    /// ```
+    pub fn get_time(&mut self) -> Result<Option<u32>> {
+        let line = self.socket.execute("get_time")?;
+
+        Ok(line.trim().parse().ok())
+    }
+
+    /// Gets the elapsed time since the track's beginning as a [`Duration`].
+    ///
+    /// VLC reports the time in whole seconds. Returns `None` if the current track is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// if let Some(elapsed) = player.get_time_duration().unwrap() {
+    ///     println!("{:?} into the track", elapsed);
+    /// }
+    /// ```
+    pub fn get_time_duration(&mut self) -> Result<Option<Duration>> {
+        Ok(self.get_time()?.map(|secs| Duration::from_secs(secs.into())))
+    }
+
+    /// Gets the current track's length (in seconds).
+    ///
+    /// Returns `None` if the current track is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let seconds = player.get_length().unwrap();
+    /// ```
+    pub fn get_length(&mut self) -> Result<Option<u32>> {
+        let line = self.socket.execute("get_length")?;
+
+        Ok(line.trim().parse().ok())
+    }
+
+    /// Gets how far into the current track the playback is, from `0.0` at its beginning to `1.0` at its end.
+    ///
+    /// Returns `None` if the current track is stopped, or if its length is unknown (e.g. for live streams).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// if let Some(position) = player.position().unwrap() {
+    ///     println!("{:.0}% done", position * 100.0);
+    /// }
+    /// ```
+    pub fn position(&mut self) -> Result<Option<f32>> {
+        let (time, length) = match (self.get_time()?, self.get_length()?) {
+            (Some(time), Some(length)) if length > 0 => (time, length),
+            _ => return Ok(None),
+        };
+
+        // The time and length are read separately, so the time may have moved past the end in between.
+        Ok(Some((time as f32 / length as f32).min(1.0)))
+    }
+
+    /// Pauses the current track if needed, and advances it by a single frame.
+    ///
+    /// Returns the elapsed time since the track's beginning (in seconds) after the step, or `None` if the current track is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let seconds = player.frame_step().unwrap();
+    /// player.resume().unwrap();
+    /// ```
+    pub fn frame_step(&mut self) -> Result<Option<u32>> {
+        self.frame_step_n(1)
+    }
+
+    /// Pauses the current track if needed, and advances it by the given amount of frames.
+    ///
+    /// Returns the elapsed time since the track's beginning (in seconds) after the steps, or `None` if the current track is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// // Step through a second of 25fps footage.
+    /// let seconds = player.frame_step_n(25).unwrap();
+    /// player.resume().unwrap();
+    /// ```
+    pub fn frame_step_n(&mut self, n: u32) -> Result<Option<u32>> {
+        // VLC pauses by itself when stepping, but pausing first keeps the track from moving on between the steps.
+        self.pause()?;
+
+        if self.state()? == PlaybackState::Paused {
+            for _ in 0..n {
+                self.socket.execute("frame")?;
+            }
+        }
+        self.get_time()
+    }
+
+    /// Moves the track's playback to the given position (in seconds).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// // Move playback to the 60 seconds position
+    /// player.seek(60).unwrap()
+    /// ```
+    pub fn seek(&mut self, secs: u32) -> Result<()> {
+        self.socket.execute(&format!("seek {}", secs))?;
+
+        Ok(())
+    }
+
+    /// Moves the track's playback forward by the given amount (in seconds).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.forward(5).unwrap();
+    /// ```
+    pub fn forward(&mut self, secs: u32) -> Result<()> {
+        self.socket.execute(&format!("seek +{}", secs))?;
+
+        Ok(())
+    }
+
+    /// Moves the track's playback backward by the given amount (in seconds).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.rewind(5).unwrap();
+    /// ```
+    pub fn rewind(&mut self, secs: u32) -> Result<()> {
+        self.socket.execute(&format!("seek -{}", secs))?;
+
+        Ok(())
+    }
+
+    /// Moves the track's playback to the given position.
+    ///
+    /// VLC seeks in whole seconds, so any fraction of a second is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.seek_duration(Duration::from_secs(60)).unwrap();
+    /// ```
+    pub fn seek_duration(&mut self, pos: Duration) -> Result<()> {
+        self.socket.execute(&format!("seek {}", pos.as_secs()))?;
+
+        Ok(())
+    }
+
+    /// Moves the track's playback forward by the given amount.
+    ///
+    /// VLC seeks in whole seconds, so any fraction of a second is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.forward_duration(Duration::from_secs(5)).unwrap();
+    /// ```
+    pub fn forward_duration(&mut self, amt: Duration) -> Result<()> {
+        self.socket.execute(&format!("seek +{}", amt.as_secs()))?;
+
+        Ok(())
+    }
+
+    /// Moves the track's playback backward by the given amount.
+    ///
+    /// VLC seeks in whole seconds, so any fraction of a second is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.rewind_duration(Duration::from_secs(5)).unwrap();
+    /// ```
+    pub fn rewind_duration(&mut self, amt: Duration) -> Result<()> {
+        self.socket.execute(&format!("seek -{}", amt.as_secs()))?;
+
+        Ok(())
+    }
+
+    /// Gets the current media track's title.
+    ///
+    /// Returns `None` if the media player is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let current_track = player.get_title().unwrap();
+    /// if let Some(title) = current_track {
+    ///     println!("the track '{}' is currently playing!", title);
+    /// }
+    /// ```
+    pub fn get_title(&mut self) -> Result<Option<String>> {
+        let line = self.socket.execute("get_title")?;
+
+        // If the line is empty, it means that the player is currently stopped - so we can just return `None`.
+        if !line.trim().is_empty() {
+            Ok(Some(line.trim().to_owned()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Plays the next track in the playlist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.next().unwrap();
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<()> {
+        self.socket.execute("next")?;
+
+        Ok(())
+    }
+
+    /// Plays the previous track in the playlist.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.prev().unwrap();
+    /// ```
+    pub fn prev(&mut self) -> Result<()> {
+        self.socket.execute("prev")?;
+
+        Ok(())
+    }
+
+    /// Gets the current input's title.
+    ///
+    /// Returns [`Error::Unavailable`] if the current input has no titles.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let title = player.title().unwrap();
+    /// println!("playing title {}", title);
+    /// ```
+    pub fn title(&mut self) -> Result<Section> {
+        self.section("title", "titles")
+    }
+
+    /// Jumps to the current input's title with the given number.
+    ///
+    /// Returns [`Error::Unavailable`] if the current input has no such title.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.set_title(1).unwrap();
+    /// assert_eq!(player.title().unwrap().number(), 1);
+    /// ```
+    pub fn set_title(&mut self, number: u32) -> Result<Section> {
+        self.set_section("title", "titles", number)
+    }
+
+    /// Jumps to the current input's next title, and returns it.
+    ///
+    /// Returns [`Error::Unavailable`] if the current input has no titles.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let title = player.title_n().unwrap();
+    /// println!("skipped to title {}", title);
+    /// ```
+    pub fn title_n(&mut self) -> Result<Section> {
+        self.step_section("title", "titles", "title_n")
+    }
+
+    /// Jumps to the current input's previous title, and returns it.
+    ///
+    /// Returns [`Error::Unavailable`] if the current input has no titles.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let title = player.title_p().unwrap();
+    /// println!("went back to title {}", title);
+    /// ```
+    pub fn title_p(&mut self) -> Result<Section> {
+        self.step_section("title", "titles", "title_p")
+    }
+
+    /// Gets the current input's chapter.
+    ///
+    /// Returns [`Error::Unavailable`] if the current input has no chapters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let chapter = player.chapter().unwrap();
+    /// println!("playing chapter {}", chapter);
+    /// ```
+    pub fn chapter(&mut self) -> Result<Section> {
+        self.section("chapter", "chapters")
+    }
+
+    /// Jumps to the current input's chapter with the given number.
+    ///
+    /// Returns [`Error::Unavailable`] if the current input has no such chapter.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.set_chapter(2).unwrap();
+    /// assert_eq!(player.chapter().unwrap().number(), 2);
+    /// ```
+    pub fn set_chapter(&mut self, number: u32) -> Result<Section> {
+        self.set_section("chapter", "chapters", number)
+    }
+
+    /// Jumps to the current input's next chapter, and returns it.
+    ///
+    /// Returns [`Error::Unavailable`] if the current input has no chapters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let chapter = player.chapter_n().unwrap();
+    /// println!("skipped to chapter {}", chapter);
+    /// ```
+    pub fn chapter_n(&mut self) -> Result<Section> {
+        self.step_section("chapter", "chapters", "chapter_n")
+    }
+
+    /// Jumps to the current input's previous chapter, and returns it.
+    ///
+    /// Returns [`Error::Unavailable`] if the current input has no chapters.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// let chapter = player.chapter_p().unwrap();
+    /// println!("went back to chapter {}", chapter);
+    /// ```
+    pub fn chapter_p(&mut self) -> Result<Section> {
+        self.step_section("chapter", "chapters", "chapter_p")
+    }
+
+    /// Reads the current section of the given kind - either `title` or `chapter`.
+    fn section(&mut self, kind: &str, plural: &'static str) -> Result<Section> {
+        let line = self.socket.execute(kind)?;
+
+        // VLC doesn't answer at all without an input, and reports a count of 0 for inputs without any sections.
+        if line.trim().is_empty() {
+            return Err(Error::Unavailable(plural));
+        }
+        let section = Section::from_parts(&line).ok_or(Error::ParseErr)?;
+        if section.count() == Some(0) {
+            return Err(Error::Unavailable(plural));
+        }
+        Ok(section)
+    }
+
+    /// Jumps to the section of the given kind with the given number, and reads it back.
+    fn set_section(
+        &mut self,
+        kind: &str,
+        plural: &'static str,
+        number: u32,
+    ) -> Result<Section> {
+        let current = self.section(kind, plural)?;
+        if current.count().is_some_and(|count| number >= count) {
+            return Err(Error::Unavailable(plural));
+        }
+
+        self.socket.execute(&format!("{} {}", kind, number))?;
+        self.section(kind, plural)
+    }
+
+    /// Issues the given command to step through the sections of the given kind, and reads the new section back.
+    fn step_section(
+        &mut self,
+        kind: &str,
+        plural: &'static str,
+        cmd: &str,
+    ) -> Result<Section> {
+        // Make sure there is something to step through before issuing the command.
+        self.section(kind, plural)?;
+
+        self.socket.execute(cmd)?;
+        self.section(kind, plural)
+    }
+
+    /// Toggles the media player's fullscreen mode on/off.
+    ///
+    ///  # Examples
+    ///
+    /// ```
+    /// use vlc_rc::Client;
+    ///
+    /// let mut player = Client::connect("127.0.0.1:9090").unwrap();
+    ///
+    /// player.fullscreen(true).unwrap();
+    /// println!("fullscreen is on!");
+    ///
+    /// player.fullscreen(false).unwrap();
+    /// println!("fullscreen is off!");
+    /// ```
+    pub fn fullscreen(&mut self, on: bool) -> Result<()> {
+        self.socket.execute(&format!(
+            "fullscreen {}",
+            if on { "on" } else { "off" }
+        ))?;
+
+        self.fullscreen = Some(on);
+        Ok(())
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        let _ = self.socket.shutdown();
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::collections::VecDeque;
     use std::env;
+    use std::io::prelude::*;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use std::time::Duration;
 
+    use super::Backoff;
     use super::Client;
+    use super::ClientBuilder;
+    use super::PlaybackState;
+    use super::PlayerEvent;
+    use super::ReconnectingClient;
     use super::Result;
+    use super::SortKey;
+    use super::SortOrder;
+    use super::StatusChange;
+    use super::Transport;
+    use crate::client::framing::split_canned;
+    use crate::Error;
 
     fn connect() -> Result<Client> {
         let addr = env::var("TEST_ADDR")
@@ -446,6 +1923,660 @@ mod test {
         Client::connect(addr)
     }
 
+    /// An in-memory transport that replays canned VLC output - what it starts with straight away, and then one response for every command sent to it, like VLC does.
+    struct Replay {
+        output: VecDeque<u8>,
+        responses: VecDeque<Vec<u8>>,
+        /// Everything written to the transport, shared so that tests can check it after handing the transport to a client.
+        written: Arc<Mutex<Vec<u8>>>,
+    }
+
+    impl Replay {
+        /// Creates a transport that starts with the `initial` output, and then answers commands with the given responses in order.
+        fn new(initial: &[u8], responses: VecDeque<Vec<u8>>) -> Replay {
+            Self {
+                output: initial.iter().copied().collect(),
+                responses,
+                written: Arc::default(),
+            }
+        }
+    }
+
+    impl Read for Replay {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let amt = self.fill_buf()?.read(buf)?;
+            self.consume(amt);
+            Ok(amt)
+        }
+    }
+
+    impl BufRead for Replay {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            Ok(self.output.as_slices().0)
+        }
+
+        fn consume(&mut self, amt: usize) {
+            self.output.drain(..amt);
+        }
+    }
+
+    impl Write for Replay {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.written.lock().unwrap().extend_from_slice(buf);
+            for _ in buf.iter().filter(|&&b| b == b'\n') {
+                if let Some(res) = self.responses.pop_front() {
+                    self.output.extend(res);
+                }
+            }
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Transport for Replay {}
+
+    /// The greeting VLC gives a client when it connects.
+    const GREETING: &str = "VLC media player 3.0.16 Vetinari\r\nCommand Line Interface initialized. Type `help' for help.\r\n> ";
+
+    /// Creates a client that logs in to a telnet interface which replays the given output.
+    fn telnet(output: &[u8], password: &str) -> Result<Client> {
+        let mut socket = super::IoSocket::new(Replay::new(
+            b"Password: \xff\xfb\x01",
+            split_canned(output),
+        ));
+        socket.login(password)?;
+        Ok(Client::from_socket(socket))
+    }
+
+    /// Creates a client that answers its commands with the responses in the given output, after VLC's greeting.
+    fn replay(output: &str) -> Result<Client> {
+        Client::from_transport(Replay::new(
+            GREETING.as_bytes(),
+            split_canned(output.as_bytes()),
+        ))
+    }
+
+    /// Like [`replay`], but also returns the commands the client writes, one per line.
+    fn recording(output: &str) -> Result<(Client, Arc<Mutex<Vec<u8>>>)> {
+        let transport =
+            Replay::new(GREETING.as_bytes(), split_canned(output.as_bytes()));
+        let written = Arc::clone(&transport.written);
+        Ok((Client::from_transport(transport)?, written))
+    }
+
+    /// Builds VLC's `+----[ <name> ]` listing of the given items, followed by the prompt - the item with the `active` key is marked with a `*`.
+    fn listing<K>(name: &str, items: &[(K, &str)], active: K) -> String
+    where
+        K: PartialEq + std::fmt::Display,
+    {
+        let mut out = format!("+----[ {} ]\r\n", name);
+        for (key, title) in items {
+            let marker = if *key == active { " *" } else { "" };
+            out += &format!("| {} - {}{}\r\n", key, title, marker);
+        }
+        out + &format!("+----[ end of {} ]\r\n> ", name)
+    }
+
+    #[test]
+    fn from_transport() -> Result<()> {
+        let mut client = replay("42\r\n> ")?;
+
+        assert_eq!(client.get_volume()?, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn playlist_with_prompt_in_title() -> Result<()> {
+        let mut client = replay(
+            "+----[ Playlist - playlist ]\r\n\
+             | 1 - Playlist\r\n\
+             |   4 - A > B.mp3 (00:00:30)\r\n\
+             |   5 - C | D.mp3 (00:01:00)\r\n\
+             | 2 - Media Library\r\n\
+             +----[ End of playlist ]\r\n\
+             > 42\r\n\
+             > ",
+        )?;
+
+        let playlist = client.playlist()?;
+        assert_eq!(playlist.len(), 2);
+        assert_eq!(playlist[0].title(), "A > B.mp3");
+        assert_eq!(playlist[1].title(), "C | D.mp3");
+
+        // The following command must not pick up any of the playlist's output.
+        assert_eq!(client.get_volume()?, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn title_starting_with_prompt() -> Result<()> {
+        let mut client = Client::from_transport(Replay::new(
+            GREETING.as_bytes(),
+            VecDeque::from([
+                b"> Intro.mp3\r\n> ".to_vec(),
+                b"42\r\n> ".to_vec(),
+            ]),
+        ))?;
+
+        assert_eq!(client.get_title()?, Some("> Intro.mp3".into()));
+        assert_eq!(client.get_volume()?, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn enqueue_returns_new_track() -> Result<()> {
+        let mut client = replay(
+            "|   4 - a.mp3 (00:00:30)\r\n> \
+             > \
+             |   4 - a.mp3 (00:00:30)\r\n|   5 - b.mp3 (00:01:00)\r\n> ",
+        )?;
+
+        let track = client.enqueue("file:///b.mp3")?;
+        assert_eq!(track.index(), 5);
+        assert_eq!(track.title(), "b.mp3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn enqueue_stream_without_length() -> Result<()> {
+        let mut client = replay(
+            "+----[ Playlist - playlist ]\r\n\
+             | 1 - Playlist\r\n\
+             |   4 - a.mp3 (00:00:30)\r\n\
+             | 2 - Media Library\r\n\
+             +----[ End of playlist ]\r\n> \
+             > \
+             +----[ Playlist - playlist ]\r\n\
+             | 1 - Playlist\r\n\
+             |   4 - a.mp3 (00:00:30)\r\n\
+             |   5 - https://example.com/stream.mp3\r\n\
+             | 2 - Media Library\r\n\
+             +----[ End of playlist ]\r\n> ",
+        )?;
+
+        let track = client.enqueue("https://example.com/stream.mp3")?;
+        assert_eq!(track.index(), 5);
+        assert_eq!(track.title(), "https://example.com/stream.mp3");
+        assert_eq!(track.length(), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn goto_verifies_current_track() -> Result<()> {
+        let mut client = replay(
+            "> \
+             |   *4 - a.mp3 (00:00:30)\r\n|   5 - b.mp3 (00:01:00)\r\n> \
+             |   4 - a.mp3 (00:00:30)\r\n|   *5 - b.mp3 (00:01:00)\r\n> ",
+        )?;
+
+        client.goto(5)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn goto_stream_without_length() -> Result<()> {
+        let mut client = replay(
+            "> \
+             |   4 - a.mp3 (00:00:30)\r\n|   *5 - https://example.com/stream.mp3\r\n> ",
+        )?;
+
+        client.goto(5)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn delete_stream_without_length() -> Result<()> {
+        let mut client = replay(
+            "> \
+             |   4 - a.mp3 (00:00:30)\r\n|   5 - https://example.com/stream.mp3\r\n> \
+             |   4 - a.mp3 (00:00:30)\r\n> ",
+        )?;
+
+        client.delete(5)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn move_item_verifies_position() -> Result<()> {
+        let mut client = replay(
+            "> \
+             |   4 - a.mp3 (00:00:30)\r\n|   5 - b.mp3 (00:01:00)\r\n> \
+             |   5 - b.mp3 (00:01:00)\r\n|   4 - a.mp3 (00:00:30)\r\n> ",
+        )?;
+
+        client.move_item(4, 5)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn sort_descending() -> Result<()> {
+        let mut client = replay(
+            "> \
+             | 1 - Playlist\r\n\
+             |   4 - a.mp3 (00:00:30)\r\n\
+             |   5 - b.mp3 (00:01:00)\r\n\
+             |   6 - c.mp3 (00:01:00)\r\n\
+             | 2 - Media Library\r\n\
+             |   7 - d.mp3 (00:01:00)\r\n> \
+             > \
+             > \
+             | 1 - Playlist\r\n\
+             |   6 - c.mp3 (00:01:00)\r\n\
+             |   5 - b.mp3 (00:01:00)\r\n\
+             |   4 - a.mp3 (00:00:30)\r\n\
+             | 2 - Media Library\r\n\
+             |   7 - d.mp3 (00:01:00)\r\n> ",
+        )?;
+
+        client.sort(SortKey::Title, SortOrder::Descending)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn clear_leaves_media_library() -> Result<()> {
+        let mut client = replay(
+            "> \
+             | 1 - Playlist\r\n\
+             | 2 - Media Library\r\n\
+             |   7 - d.mp3 (00:01:00)\r\n> ",
+        )?;
+
+        client.clear()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn sort_invalid_key() -> Result<()> {
+        let mut client = replay("Invalid sort key: title\r\n> ")?;
+
+        assert!(matches!(
+            client.sort(SortKey::Title, SortOrder::Ascending),
+            Err(Error::CommandIgnored)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_loop_is_idempotent() -> Result<()> {
+        let (mut client, written) = recording("> > ")?;
+
+        client.set_loop(true)?;
+        client.set_loop(true)?;
+
+        assert_eq!(*written.lock().unwrap(), b"loop on\nloop on\n");
+        assert_eq!(client.playback_modes().looping(), Some(true));
+        assert_eq!(client.playback_modes().repeat(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn pause_is_idempotent() -> Result<()> {
+        let mut client = replay(
+            "( audio volume: 256 )\r\n( state playing )\r\n> \
+             > \
+             ( audio volume: 256 )\r\n( state paused )\r\n> \
+             ( audio volume: 256 )\r\n( state paused )\r\n> \
+             ( audio volume: 256 )\r\n( state paused )\r\n> ",
+        )?;
+
+        client.pause()?;
+        client.pause()?;
+        assert_eq!(client.state()?, PlaybackState::Paused);
+
+        Ok(())
+    }
+
+    #[test]
+    fn state_without_input_is_stopped() -> Result<()> {
+        let mut client = replay("> ")?;
+
+        assert_eq!(client.state()?, PlaybackState::Stopped);
+
+        Ok(())
+    }
+
+    #[test]
+    fn chapter_n_returns_next_chapter() -> Result<()> {
+        let mut client = replay(
+            "Currently playing chapter 1/4.\r\n> \
+             > \
+             Currently playing chapter 2/4.\r\n> ",
+        )?;
+
+        let chapter = client.chapter_n()?;
+        assert_eq!(chapter.number(), 2);
+        assert_eq!(chapter.count(), Some(4));
+
+        Ok(())
+    }
+
+    #[test]
+    fn title_without_titles() -> Result<()> {
+        let mut client = replay("Currently playing title 0/0.\r\n> > ")?;
+
+        assert!(matches!(client.title(), Err(Error::Unavailable("titles"))));
+        assert!(matches!(client.title(), Err(Error::Unavailable("titles"))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_rate_verifies_rate() -> Result<()> {
+        let (mut client, written) =
+            recording("> 1.000000\r\n> 1.501502\r\n> ")?;
+
+        client.set_rate(1.5)?;
+
+        assert_eq!(*written.lock().unwrap(), b"rate 1.5\nrate\nrate\n");
+        Ok(())
+    }
+
+    #[test]
+    fn set_rate_accepts_quantised_rate() -> Result<()> {
+        let mut client = replay("> 7.042254\r\n> ")?;
+
+        client.set_rate(7.0)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn faster_waits_for_rate_change() -> Result<()> {
+        let (mut client, written) =
+            recording("1.000000\r\n> > 1.000000\r\n> 1.500000\r\n> ")?;
+
+        assert_eq!(client.faster()?, 1.5);
+
+        assert_eq!(*written.lock().unwrap(), b"rate\nfaster\nrate\nrate\n");
+        Ok(())
+    }
+
+    #[test]
+    fn slower_at_slowest_rate() -> Result<()> {
+        // The rate is read once before the command, and then on every attempt to see it change.
+        let rate = "0.031250\r\n> ";
+        let mut client = replay(&format!(
+            "{}> {}",
+            rate,
+            rate.repeat(Client::RETRY_ATTEMPTS)
+        ))?;
+
+        assert!(matches!(client.slower(), Err(Error::CommandIgnored)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_rate_out_of_range() -> Result<()> {
+        let mut client = replay("")?;
+
+        assert!(matches!(client.set_rate(32.0), Err(Error::OutOfRange)));
+        assert!(matches!(client.set_rate(0.0), Err(Error::OutOfRange)));
+        assert!(matches!(client.set_rate(f32::NAN), Err(Error::OutOfRange)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn frame_step_n_pauses_first() -> Result<()> {
+        let mut client = replay(
+            "( state playing )\r\n> \
+             > \
+             ( state paused )\r\n> \
+             ( state paused )\r\n> \
+             > > > \
+             12\r\n> ",
+        )?;
+
+        assert_eq!(client.frame_step_n(3)?, Some(12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn position_is_fraction_of_length() -> Result<()> {
+        let mut client = replay("15\r\n> 60\r\n> \r\n> \r\n> ")?;
+
+        assert_eq!(client.position()?, Some(0.25));
+        assert_eq!(client.position()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_audio_track_verifies_active_track() -> Result<()> {
+        let tracks = |active| {
+            listing(
+                "audio-es",
+                &[
+                    (-1, "Disable"),
+                    (1, "Track 1 - [English]"),
+                    (2, "Track 2 - [French]"),
+                ],
+                active,
+            )
+        };
+        let mut client = replay(&format!(
+            "{}> {}{}{}",
+            tracks(1),
+            tracks(1),
+            tracks(2),
+            tracks(2)
+        ))?;
+
+        client.set_audio_track(2)?;
+        assert!(matches!(client.set_audio_track(3), Err(Error::OutOfRange)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_video_track_disables_video() -> Result<()> {
+        let tracks = |active| {
+            listing("video-es", &[(-1, "Disable"), (0, "Track 1")], active)
+        };
+        let mut client = replay(&format!("{}> {}", tracks(0), tracks(-1)))?;
+
+        client.set_video_track(-1)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_subtitle_and_disable() -> Result<()> {
+        let stracks = |active| {
+            listing(
+                "spu-es",
+                &[(-1, "Disable"), (2, "Track 1 - [English]")],
+                active,
+            )
+        };
+        let mut client = replay(&format!(
+            "{}> {}{}{}> {}{}",
+            stracks(-1),
+            stracks(2),
+            stracks(2),
+            stracks(2),
+            stracks(-1),
+            stracks(-1)
+        ))?;
+
+        client.set_subtitle(2)?;
+        assert_eq!(client.current_subtitle()?.map(|s| s.index()), Some(2));
+
+        client.disable_subtitles()?;
+        assert_eq!(client.current_subtitle()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_audio_device_verifies_active_device() -> Result<()> {
+        let devices = |active| {
+            listing("adev", &[("", "Default"), ("hdmi", "HDMI Output")], active)
+        };
+        let mut client = replay(&format!(
+            "{}> {}{}{}",
+            devices(""),
+            devices(""),
+            devices("hdmi"),
+            devices("hdmi")
+        ))?;
+
+        let devices = client.set_audio_device("hdmi")?;
+        assert!(devices[1].is_active());
+        assert!(client.audio_devices()?[1].is_active());
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_audio_device_empty_id() -> Result<()> {
+        let (mut client, written) = recording("")?;
+
+        assert!(matches!(
+            client.set_audio_device(""),
+            Err(Error::InvalidCommand)
+        ));
+        assert!(written.lock().unwrap().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_audio_channel_unknown_mode() -> Result<()> {
+        let mut client =
+            replay(&listing("achan", &[(1, "Stereo"), (7, "Mono")], 1))?;
+
+        assert!(matches!(client.set_audio_channel(2), Err(Error::OutOfRange)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn status_changes_are_queued() -> Result<()> {
+        let mut client = replay(
+            "status change: ( audio volume: 256 )\r\n42\r\n> ( state playing )\r\n1\r\n> ",
+        )?;
+
+        assert_eq!(client.get_volume()?, 42);
+        assert!(client.is_playing()?);
+        assert_eq!(
+            client.status_changes().collect::<Vec<_>>(),
+            [StatusChange::Volume(256), StatusChange::State("playing".into())]
+        );
+        assert_eq!(client.status_changes().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn watch_changes() -> Result<()> {
+        let mut client = replay(
+            "a.mp3\r\n> 10\r\n> 50\r\n> 1\r\n> \
+             |   4 - a.mp3 (00:00:30)\r\n> \
+             b.mp3\r\n> 0\r\n> 60\r\n> 1\r\n> \
+             |   4 - a.mp3 (00:00:30)\r\n|   5 - b.mp3 (00:01:00)\r\n> \
+             b.mp3\r\n> 40\r\n> 60\r\n> 0\r\n> \
+             |   4 - a.mp3 (00:00:30)\r\n|   5 - b.mp3 (00:01:00)\r\n> ",
+        )?;
+
+        let events =
+            client.watch(Duration::ZERO).take(5).collect::<Result<Vec<_>>>()?;
+
+        assert_eq!(events[0], PlayerEvent::TrackChanged(Some("b.mp3".into())));
+        assert_eq!(events[1], PlayerEvent::VolumeChanged(60));
+        assert!(
+            matches!(events[2], PlayerEvent::PlaylistChanged(ref p) if p.len() == 2)
+        );
+        assert_eq!(events[3], PlayerEvent::Seeked(40));
+        assert_eq!(events[4], PlayerEvent::Stopped);
+
+        Ok(())
+    }
+
+    #[test]
+    fn telnet_login() -> Result<()> {
+        let mut client = telnet(
+            b"\xff\xfc\x01\r\nWelcome, Master\r\n> media : ( 0 broadcast - 0 vod )\r\n> 42\r\n> ",
+            "secret",
+        )?;
+
+        assert_eq!(client.vlm("show")?, "media : ( 0 broadcast - 0 vod )");
+        assert_eq!(client.get_volume()?, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn telnet_wrong_password() {
+        let client = telnet(
+            b"\xff\xfc\x01\r\nWrong password\r\nPassword: \xff\xfb\x01",
+            "wrong",
+        );
+
+        assert!(matches!(client, Err(Error::Auth)));
+    }
+
+    #[test]
+    fn reconnect_after_eof() -> Result<()> {
+        // The first connection closes after the volume has been observed, while the second one starts at a different volume that must be restored before the failed command is retried.
+        let mut outputs = vec!["7\r\n> ", "42\r\n> > 7\r\n> 7\r\n> "];
+        outputs.reverse();
+
+        let mut client = ReconnectingClient::new(move || {
+            replay(outputs.pop().expect("too many reconnects"))
+        })?
+        .backoff(Backoff {
+            initial: Duration::ZERO,
+            max: Duration::ZERO,
+            attempts: 1,
+        });
+
+        assert_eq!(client.run(|c| c.get_volume())?, 7);
+        assert_eq!(client.run(|c| c.get_volume())?, 7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn builder_without_greeting() -> Result<()> {
+        let mut client = ClientBuilder::new()
+            .greeting(false)
+            .from_transport(Replay::new(b"", split_canned(b"42\r\n> ")))?;
+
+        assert_eq!(client.get_volume()?, 42);
+
+        Ok(())
+    }
+
+    #[test]
+    fn builder_without_greeting_skips_greeting() -> Result<()> {
+        let mut client = ClientBuilder::new().greeting(false).from_transport(
+            Replay::new(GREETING.as_bytes(), split_canned(b"42\r\n> 7\r\n> ")),
+        )?;
+
+        assert_eq!(client.get_volume()?, 42);
+        assert_eq!(client.get_volume()?, 7);
+
+        Ok(())
+    }
+
     #[test]
     fn get_and_set_volume() -> Result<()> {
         let mut client = connect()?;
@@ -464,10 +2595,10 @@ mod test {
         let mut client = connect()?;
 
         client.play()?;
-        assert_eq!(client.is_playing()?, true);
+        assert!(client.is_playing()?);
 
         client.stop()?;
-        assert_eq!(client.is_playing()?, false);
+        assert!(!client.is_playing()?);
 
         Ok(())
     }
@@ -536,7 +2667,7 @@ mod test {
             _ => return Ok(()),
         };
 
-        assert_eq!(after, (before).checked_sub(5).unwrap_or(0));
+        assert_eq!(after, before.saturating_sub(5));
 
         Ok(())
     }
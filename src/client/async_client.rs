@@ -0,0 +1,758 @@
+use std::future::Future;
+use std::io::ErrorKind;
+use std::time::Duration;
+
+use tokio::io::AsyncBufRead;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufStream;
+use tokio::net::TcpStream;
+use tokio::net::ToSocketAddrs;
+use tokio::time::sleep;
+use tokio::time::timeout;
+
+use crate::Error;
+use crate::Result;
+
+use super::framing::check_command;
+use super::framing::Framer;
+use super::media::FromParts;
+use super::Client;
+use super::ClientBuilder;
+use super::PlaybackState;
+use super::Playlist;
+use super::StatusChange;
+use super::Subtitle;
+use super::Subtitles;
+use super::Track;
+use super::MAX_VOLUME;
+
+/// An asynchronous connection to a VLC player's interface, built on [tokio](https://tokio.rs).
+///
+/// Offers the most common methods of [`Client`](super::Client), but never blocks the calling thread while waiting on VLC. It talks to VLC over any buffered tokio stream - a TCP connection by default.
+pub struct AsyncClient<S = BufStream<TcpStream>> {
+    stream: S,
+    framer: Framer,
+    read_timeout: Option<Duration>,
+    write_timeout: Option<Duration>,
+}
+
+impl AsyncClient {
+    /// Establishes a connection to a VLC player's TCP interface at the given address.
+    ///
+    /// Use [`ClientBuilder::connect_async`] to configure the connection's timeouts and socket options.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let player = AsyncClient::connect("127.0.0.1:9090").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect<A>(addr: A) -> Result<AsyncClient>
+    where
+        A: ToSocketAddrs,
+    {
+        ClientBuilder::new().connect_async(addr).await
+    }
+}
+
+impl<S> AsyncClient<S>
+where
+    S: AsyncBufRead + AsyncWrite + Unpin,
+{
+    /// Starts a session with a VLC player over the given stream, such as an in-memory pipe.
+    ///
+    /// Use [`ClientBuilder::from_async_stream`] to configure the session's timeouts and greeting.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tokio::io::BufStream;
+    /// use tokio::net::UnixStream;
+    ///
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let stream = UnixStream::connect("/run/vlc.sock").await?;
+    /// let player = AsyncClient::from_stream(BufStream::new(stream)).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn from_stream(stream: S) -> Result<AsyncClient<S>> {
+        ClientBuilder::new().from_async_stream(stream).await
+    }
+
+    /// Starts a session over the given stream with the given timeouts, consuming VLC's greeting if `greeting` is `true`.
+    pub(crate) async fn new(
+        stream: S,
+        read_timeout: Option<Duration>,
+        write_timeout: Option<Duration>,
+        greeting: bool,
+    ) -> Result<AsyncClient<S>> {
+        let mut client =
+            Self { stream, framer: Framer::new(), read_timeout, write_timeout };
+
+        if greeting {
+            client.framer.expect_response();
+            client.next_response().await?;
+        } else {
+            client.framer.skip_greeting();
+        }
+        Ok(client)
+    }
+
+    /// Removes and returns the status change notifications VLC has sent so far, oldest first.
+    ///
+    /// See [`Client::status_changes`](super::Client::status_changes).
+    pub fn status_changes(
+        &mut self,
+    ) -> impl Iterator<Item = StatusChange> + '_ {
+        self.framer.status_changes()
+    }
+
+    /// Gets a list of tracks in the VLC player's playlist.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// let playlist = player.playlist().await?;
+    /// for track in playlist {
+    ///     println!("{}", track);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn playlist(&mut self) -> Result<Playlist> {
+        let out = self.execute("playlist").await?;
+
+        Ok(out.lines().filter_map(Track::from_parts).collect())
+    }
+
+    /// Gets a list of subtitle tracks for the current media file.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// let subtitles = player.subtitles().await?;
+    /// for strack in subtitles {
+    ///     println!("{}", strack);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn subtitles(&mut self) -> Result<Subtitles> {
+        let out = self.execute("strack").await?;
+
+        Ok(out.lines().filter_map(Subtitle::from_parts).collect())
+    }
+
+    /// Gets the VLC player's current volume.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// let volume = player.get_volume().await?;
+    /// println!("the current volume is {}", volume);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_volume(&mut self) -> Result<u8> {
+        let volume = self.execute("volume").await?.trim().parse::<u16>()?;
+
+        if volume <= (MAX_VOLUME as u16) {
+            Ok(volume as u8)
+        } else {
+            Ok(MAX_VOLUME)
+        }
+    }
+
+    /// Sets the VLC player's volume to the given amount.
+    ///
+    /// If `amt` is greater than [`MAX_VOLUME`], it defaults to the max volume.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.set_volume(50).await?;
+    /// assert_eq!(player.get_volume().await?, 50);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_volume(&mut self, mut amt: u8) -> Result<()> {
+        if amt > MAX_VOLUME {
+            amt = MAX_VOLUME;
+        }
+
+        // Spam the interface until we get the desired output.
+        while self.get_volume().await? != amt {
+            self.execute(&format!("volume {}", amt)).await?;
+        }
+        Ok(())
+    }
+
+    /// Returns whether or not the current media track is playing.
+    ///
+    /// Note that if the track is paused, the method still returns `true`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// if player.is_playing().await? {
+    ///     println!("the track is currently playing!");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_playing(&mut self) -> Result<bool> {
+        Ok(self.execute("is_playing").await?.trim() == "1")
+    }
+
+    /// Gets whether the player is playing, paused or stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    /// use vlc_rc::client::PlaybackState;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// if player.state().await? == PlaybackState::Paused {
+    ///     println!("the track is currently paused!");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn state(&mut self) -> Result<PlaybackState> {
+        self.send("status").await?;
+        let out = self.next_response().await?;
+
+        // VLC doesn't report a state when there's no input, which is the same as being stopped.
+        Ok(out
+            .lines()
+            .rev()
+            .find_map(PlaybackState::from_parts)
+            .unwrap_or(PlaybackState::Stopped))
+    }
+
+    /// Plays the current media track, resuming it if it's paused.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    /// use vlc_rc::client::PlaybackState;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.play().await?;
+    /// assert_eq!(player.state().await?, PlaybackState::Playing);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn play(&mut self) -> Result<()> {
+        // Only issue the 'play' command if the playlist is not empty.
+        if !self.playlist().await?.is_empty()
+            && self.state().await? != PlaybackState::Playing
+        {
+            self.execute("play").await?;
+            self.wait_for_state(PlaybackState::Playing).await?;
+        }
+        Ok(())
+    }
+
+    /// Stops the current media track's playback.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    /// use vlc_rc::client::PlaybackState;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.stop().await?;
+    /// assert_eq!(player.state().await?, PlaybackState::Stopped);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stop(&mut self) -> Result<()> {
+        if self.state().await? != PlaybackState::Stopped {
+            self.execute("stop").await?;
+            self.wait_for_state(PlaybackState::Stopped).await?;
+        }
+        Ok(())
+    }
+
+    /// Pauses the current track's playback.
+    ///
+    /// Does nothing if the track is already paused or stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.pause().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn pause(&mut self) -> Result<()> {
+        // The 'pause' command works as a toggle, so it's only issued when it's known to pause the track.
+        if self.state().await? == PlaybackState::Playing {
+            self.execute("pause").await?;
+            self.wait_for_state(PlaybackState::Paused).await?;
+        }
+        Ok(())
+    }
+
+    /// Resumes the current track's playback if it's paused.
+    ///
+    /// Does nothing if the track is playing or stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.resume().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resume(&mut self) -> Result<()> {
+        if self.state().await? == PlaybackState::Paused {
+            self.execute("pause").await?;
+            self.wait_for_state(PlaybackState::Playing).await?;
+        }
+        Ok(())
+    }
+
+    /// Reads the playback state until it's the given one, giving VLC some time to apply a command.
+    ///
+    /// Returns [`Error::CommandIgnored`] if it never is.
+    async fn wait_for_state(&mut self, state: PlaybackState) -> Result<()> {
+        for attempt in 0..Client::RETRY_ATTEMPTS {
+            if attempt > 0 {
+                sleep(Client::RETRY_DELAY).await;
+            }
+            if self.state().await? == state {
+                return Ok(());
+            }
+        }
+        Err(Error::CommandIgnored)
+    }
+
+    /// Gets the elapsed time since the track's beginning (in seconds).
+    ///
+    /// Returns `None` if the current track is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// let seconds = player.get_time().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_time(&mut self) -> Result<Option<u32>> {
+        Ok(self.execute("get_time").await?.trim().parse().ok())
+    }
+
+    /// Moves the track's playback to the given position (in seconds).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// // Move playback to the 60 seconds position
+    /// player.seek(60).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn seek(&mut self, secs: u32) -> Result<()> {
+        self.execute(&format!("seek {}", secs)).await?;
+        Ok(())
+    }
+
+    /// Moves the track's playback forward by the given amount (in seconds).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.forward(5).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn forward(&mut self, secs: u32) -> Result<()> {
+        self.execute(&format!("seek +{}", secs)).await?;
+        Ok(())
+    }
+
+    /// Moves the track's playback backward by the given amount (in seconds).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.rewind(5).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn rewind(&mut self, secs: u32) -> Result<()> {
+        self.execute(&format!("seek -{}", secs)).await?;
+        Ok(())
+    }
+
+    /// Gets the current media track's title.
+    ///
+    /// Returns `None` if the media player is stopped.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// if let Some(title) = player.get_title().await? {
+    ///     println!("the track '{}' is currently playing!", title);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_title(&mut self) -> Result<Option<String>> {
+        let line = self.execute("get_title").await?;
+
+        // If the line is empty, it means that the player is currently stopped - so we can just return `None`.
+        if !line.trim().is_empty() {
+            Ok(Some(line.trim().to_owned()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Plays the next track in the playlist.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.next().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub async fn next(&mut self) -> Result<()> {
+        self.execute("next").await?;
+        Ok(())
+    }
+
+    /// Plays the previous track in the playlist.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.prev().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn prev(&mut self) -> Result<()> {
+        self.execute("prev").await?;
+        Ok(())
+    }
+
+    /// Toggles the media player's fullscreen mode on/off.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use vlc_rc::client::AsyncClient;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let mut player = AsyncClient::connect("127.0.0.1:9090").await?;
+    ///
+    /// player.fullscreen(true).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fullscreen(&mut self, on: bool) -> Result<()> {
+        self.execute(if on { "fullscreen on" } else { "fullscreen off" })
+            .await?;
+        Ok(())
+    }
+
+    /// Closes the connection to VLC.
+    ///
+    /// Dropping the client also closes the connection, but without waiting for it to finish.
+    pub async fn shutdown(mut self) -> Result<()> {
+        self.stream.shutdown().await?;
+        Ok(())
+    }
+
+    /// Sends a command to VLC and reads its response, discarding the late responses to any earlier commands.
+    async fn execute(&mut self, cmd: &str) -> Result<String> {
+        self.send(cmd).await?;
+        let res = self.next_response().await?;
+
+        Ok(self.framer.route(&res))
+    }
+
+    /// Writes the given command to VLC.
+    async fn send(&mut self, cmd: &str) -> Result<()> {
+        check_command(cmd)?;
+
+        with_timeout(self.write_timeout, async {
+            self.stream.write_all(cmd.as_bytes()).await?;
+            self.stream.write_all(b"\n").await?;
+            self.stream.flush().await
+        })
+        .await?;
+        self.framer.expect_response();
+        Ok(())
+    }
+
+    /// Reads the response to the last command sent, discarding the late responses to any earlier ones.
+    async fn next_response(&mut self) -> Result<String> {
+        loop {
+            if let Some(res) = self.framer.next_response() {
+                return Ok(res);
+            }
+
+            let buf =
+                with_timeout(self.read_timeout, self.stream.fill_buf()).await?;
+            if buf.is_empty() {
+                return Err(
+                    std::io::Error::from(ErrorKind::UnexpectedEof).into()
+                );
+            }
+
+            let amt = buf.len();
+            self.framer.feed(buf);
+            self.stream.consume(amt);
+        }
+    }
+}
+
+/// Runs the given I/O operation, failing with [`ErrorKind::TimedOut`] if it takes longer than `dur`.
+///
+/// If `dur` is `None`, the operation is awaited for as long as it takes.
+pub(crate) async fn with_timeout<F, T>(dur: Option<Duration>, f: F) -> Result<T>
+where
+    F: Future<Output = std::io::Result<T>>,
+{
+    let Some(dur) = dur else {
+        return Ok(f.await?);
+    };
+
+    match timeout(dur, f).await {
+        Ok(res) => Ok(res?),
+        Err(_) => Err(std::io::Error::from(ErrorKind::TimedOut).into()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::env;
+    use std::time::Duration;
+
+    use tokio::io::duplex;
+    use tokio::io::AsyncBufReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::io::BufStream;
+    use tokio::io::DuplexStream;
+
+    use super::AsyncClient;
+    use super::ClientBuilder;
+    use super::PlaybackState;
+    use super::Result;
+    use super::StatusChange;
+    use crate::client::framing::split_canned;
+    use crate::Error;
+
+    async fn connect() -> Result<AsyncClient> {
+        let addr = env::var("TEST_ADDR")
+            .expect("use the 'test.sh' bash script to run tests!");
+
+        AsyncClient::connect(addr).await
+    }
+
+    /// Creates a client that answers its commands with the responses in the given output, after VLC's greeting, over an in-memory pipe.
+    async fn replay(
+        output: &str,
+    ) -> Result<AsyncClient<BufStream<DuplexStream>>> {
+        let greeting = "VLC media player 3.0.16 Vetinari\r\nCommand Line Interface initialized. Type `help' for help.\r\n> ";
+        let (client, server) = duplex(64 * 1024);
+        let mut responses = split_canned(output.as_bytes());
+
+        // Play VLC's end of the pipe, answering every command with the next response.
+        tokio::spawn(async move {
+            let mut server = BufStream::new(server);
+            server.write_all(greeting.as_bytes()).await?;
+            server.flush().await?;
+
+            let mut cmd = String::new();
+            while server.read_line(&mut cmd).await? > 0 {
+                if let Some(res) = responses.pop_front() {
+                    server.write_all(&res).await?;
+                    server.flush().await?;
+                }
+                cmd.clear();
+            }
+            std::io::Result::Ok(())
+        });
+
+        ClientBuilder::new()
+            .read_timeout(Some(Duration::from_millis(100)))
+            .from_async_stream(BufStream::new(client))
+            .await
+    }
+
+    #[tokio::test]
+    async fn async_from_stream() -> Result<()> {
+        let mut client =
+            replay("status change: ( audio volume: 256 )\r\n42\r\n> ").await?;
+
+        assert_eq!(client.get_volume().await?, 42);
+        assert_eq!(
+            client.status_changes().collect::<Vec<_>>(),
+            [StatusChange::Volume(256)]
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn async_pause_is_idempotent() -> Result<()> {
+        let mut client = replay(
+            "( state playing )\r\n> \
+             > \
+             ( state paused )\r\n> \
+             ( state paused )\r\n> ",
+        )
+        .await?;
+
+        client.pause().await?;
+        client.pause().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn async_read_timeout() -> Result<()> {
+        let mut client = replay("").await?;
+
+        assert!(matches!(
+            client.get_volume().await,
+            Err(Error::Io(e)) if e.kind() == std::io::ErrorKind::TimedOut
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn async_get_and_set_volume() -> Result<()> {
+        let mut client = connect().await?;
+
+        client.set_volume(25).await?;
+        assert_eq!(client.get_volume().await?, 25);
+
+        client.set_volume(0).await?;
+        assert_eq!(client.get_volume().await?, 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn async_play_and_stop() -> Result<()> {
+        let mut client = connect().await?;
+
+        client.play().await?;
+        assert_eq!(client.state().await?, PlaybackState::Playing);
+
+        client.stop().await?;
+        assert_eq!(client.state().await?, PlaybackState::Stopped);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn async_pause_and_resume() -> Result<()> {
+        let mut client = connect().await?;
+
+        client.play().await?;
+        client.pause().await?;
+        client.pause().await?;
+        assert_eq!(client.state().await?, PlaybackState::Paused);
+
+        client.resume().await?;
+        assert_eq!(client.state().await?, PlaybackState::Playing);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,281 @@
+use std::io::Error as IoError;
+use std::io::ErrorKind;
+use std::net::TcpStream;
+use std::net::ToSocketAddrs;
+use std::time::Duration;
+
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
+#[cfg(unix)]
+use std::path::Path;
+
+use socket2::SockRef;
+use socket2::TcpKeepalive;
+
+#[cfg(feature = "tokio")]
+use tokio::io::AsyncBufRead;
+#[cfg(feature = "tokio")]
+use tokio::io::AsyncWrite;
+
+use crate::Result;
+
+#[cfg(feature = "tokio")]
+use super::async_client::with_timeout;
+use super::socket::BufStream;
+use super::socket::IoSocket;
+#[cfg(feature = "tokio")]
+use super::AsyncClient;
+use super::Client;
+use super::Transport;
+
+/// A builder used to configure how a [`Client`] connects to VLC.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+///
+/// use vlc_rc::client::ClientBuilder;
+///
+/// let player = ClientBuilder::new()
+///     .connect_timeout(Some(Duration::from_secs(10)))
+///     .read_timeout(Some(Duration::from_secs(5)))
+///     .nodelay(true)
+///     .connect("127.0.0.1:9090")
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientBuilder {
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    write_timeout: Option<Duration>,
+    greeting: bool,
+    nodelay: bool,
+    keepalive: Option<Duration>,
+}
+
+impl ClientBuilder {
+    /// The default maximum amount of time that can pass before a read call is terminated.
+    pub const READ_TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// The default maximum amount of time that can pass before a write call is terminated.
+    pub const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+
+    /// Creates a new builder with the same settings that [`Client::connect`] uses.
+    ///
+    /// That is, no connect timeout, the default [read](Self::READ_TIMEOUT) and [write](Self::WRITE_TIMEOUT) timeouts, consuming VLC's greeting, and leaving the socket's options untouched.
+    pub fn new() -> ClientBuilder {
+        Self {
+            connect_timeout: None,
+            read_timeout: Some(Self::READ_TIMEOUT),
+            write_timeout: Some(Self::WRITE_TIMEOUT),
+            greeting: true,
+            nodelay: false,
+            keepalive: None,
+        }
+    }
+
+    /// Sets the maximum amount of time to wait for a TCP connection to be established.
+    ///
+    /// If `None`, the operating system's default is used.
+    pub fn connect_timeout(mut self, dur: Option<Duration>) -> ClientBuilder {
+        self.connect_timeout = dur;
+        self
+    }
+
+    /// Sets the maximum amount of time that can pass before a read call is terminated.
+    ///
+    /// If `None`, read calls block until VLC responds.
+    pub fn read_timeout(mut self, dur: Option<Duration>) -> ClientBuilder {
+        self.read_timeout = dur;
+        self
+    }
+
+    /// Sets the maximum amount of time that can pass before a write call is terminated.
+    ///
+    /// If `None`, write calls block until they complete.
+    pub fn write_timeout(mut self, dur: Option<Duration>) -> ClientBuilder {
+        self.write_timeout = dur;
+        self
+    }
+
+    /// Sets whether or not to wait for the greeting VLC gives a client when it connects.
+    ///
+    /// Turning this off is meant for peers that don't greet their clients. A greeting that arrives anyway is still skipped, so that it can't be taken as the first command's response.
+    pub fn greeting(mut self, consume: bool) -> ClientBuilder {
+        self.greeting = consume;
+        self
+    }
+
+    /// Sets the `TCP_NODELAY` option on TCP connections.
+    pub fn nodelay(mut self, nodelay: bool) -> ClientBuilder {
+        self.nodelay = nodelay;
+        self
+    }
+
+    /// Enables TCP keepalive probes on TCP connections after the connection has been idle for the given amount of time.
+    ///
+    /// If `None`, the socket's keepalive setting is left untouched.
+    pub fn keepalive(mut self, idle: Option<Duration>) -> ClientBuilder {
+        self.keepalive = idle;
+        self
+    }
+
+    /// Establishes a connection to a VLC player's TCP interface at the given address.
+    pub fn connect<A>(&self, addr: A) -> Result<Client>
+    where
+        A: ToSocketAddrs,
+    {
+        self.from_transport(BufStream::tcp(self.tcp_stream(addr)?)?)
+    }
+
+    /// Establishes a connection to a VLC player's telnet interface at the given address and logs in with the given password.
+    ///
+    /// The greeting setting doesn't apply, since logging in consumes VLC's welcome message.
+    pub fn connect_telnet<A>(&self, addr: A, password: &str) -> Result<Client>
+    where
+        A: ToSocketAddrs,
+    {
+        let mut socket = IoSocket::new(BufStream::tcp(self.tcp_stream(addr)?)?);
+        socket.login(password)?;
+        Ok(Client::from_socket(socket))
+    }
+
+    /// Opens a TCP stream to the given address with the builder's settings applied.
+    fn tcp_stream<A>(&self, addr: A) -> Result<TcpStream>
+    where
+        A: ToSocketAddrs,
+    {
+        let stream = match self.connect_timeout {
+            Some(dur) => connect_timeout(addr, dur)?,
+            None => TcpStream::connect(addr)?,
+        };
+
+        stream.set_read_timeout(self.read_timeout)?;
+        stream.set_write_timeout(self.write_timeout)?;
+        stream.set_nodelay(self.nodelay)?;
+
+        if let Some(idle) = self.keepalive {
+            SockRef::from(&stream)
+                .set_tcp_keepalive(&TcpKeepalive::new().with_time(idle))?;
+        }
+
+        Ok(stream)
+    }
+
+    /// Establishes a connection to a VLC player's Unix domain socket interface at the given path.
+    ///
+    /// The connect timeout and TCP options don't apply to Unix domain sockets.
+    #[cfg(unix)]
+    pub fn connect_unix<P>(&self, path: P) -> Result<Client>
+    where
+        P: AsRef<Path>,
+    {
+        let stream = UnixStream::connect(path)?;
+
+        stream.set_read_timeout(self.read_timeout)?;
+        stream.set_write_timeout(self.write_timeout)?;
+
+        self.from_transport(BufStream::unix(stream)?)
+    }
+
+    /// Starts a session with a VLC player over a custom [`Transport`].
+    ///
+    /// Only the greeting setting applies - the transport is responsible for its own timeouts.
+    pub fn from_transport<T>(&self, transport: T) -> Result<Client>
+    where
+        T: Transport + 'static,
+    {
+        let mut socket = IoSocket::new(transport);
+        if self.greeting {
+            socket.consume_greeting()?;
+        } else {
+            socket.skip_greeting();
+        }
+        Ok(Client::from_socket(socket))
+    }
+}
+
+#[cfg(feature = "tokio")]
+impl ClientBuilder {
+    /// Establishes an asynchronous connection to a VLC player's TCP interface at the given address (requires the `tokio` feature).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// use vlc_rc::client::ClientBuilder;
+    ///
+    /// # async fn run() -> vlc_rc::Result<()> {
+    /// let player = ClientBuilder::new()
+    ///     .read_timeout(Some(Duration::from_secs(5)))
+    ///     .connect_async("127.0.0.1:9090")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn connect_async<A>(&self, addr: A) -> Result<AsyncClient>
+    where
+        A: tokio::net::ToSocketAddrs,
+    {
+        let stream = with_timeout(
+            self.connect_timeout,
+            tokio::net::TcpStream::connect(addr),
+        )
+        .await?;
+
+        stream.set_nodelay(self.nodelay)?;
+        if let Some(idle) = self.keepalive {
+            SockRef::from(&stream)
+                .set_tcp_keepalive(&TcpKeepalive::new().with_time(idle))?;
+        }
+
+        self.from_async_stream(tokio::io::BufStream::new(stream)).await
+    }
+
+    /// Starts an asynchronous session with a VLC player over the given stream (requires the `tokio` feature).
+    ///
+    /// The TCP options don't apply, but the read and write timeouts are enforced by the client itself.
+    pub async fn from_async_stream<S>(
+        &self,
+        stream: S,
+    ) -> Result<AsyncClient<S>>
+    where
+        S: AsyncBufRead + AsyncWrite + Unpin,
+    {
+        AsyncClient::new(
+            stream,
+            self.read_timeout,
+            self.write_timeout,
+            self.greeting,
+        )
+        .await
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Connects to the first address that accepts a connection within the given timeout.
+fn connect_timeout<A>(addr: A, dur: Duration) -> std::io::Result<TcpStream>
+where
+    A: ToSocketAddrs,
+{
+    let mut last_err = None;
+    for addr in addr.to_socket_addrs()? {
+        match TcpStream::connect_timeout(&addr, dur) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_err = Some(e),
+        }
+    }
+    Err(last_err.unwrap_or_else(|| {
+        IoError::new(
+            ErrorKind::InvalidInput,
+            "could not resolve to any addresses",
+        )
+    }))
+}
@@ -0,0 +1,304 @@
+use std::collections::VecDeque;
+
+use crate::Error;
+use crate::Result;
+
+use super::media::FromParts;
+use super::media::StatusChange;
+
+/// The byte used to prompt a client for a command.
+pub const PROMPT: u8 = b'>';
+
+/// The prefix VLC gives the status change notifications it sends without being asked.
+const STATUS_CHANGE_PREFIX: &str = "status change:";
+
+/// The end of the line VLC greets a client with, e.g. `Command Line Interface initialized. Type `help' for help.`.
+const GREETING_SUFFIX: &str = "initialized. Type `help' for help.";
+
+/// The maximum amount of status changes kept until they're drained - the oldest ones are discarded first.
+const STATUS_CHANGES_CAPACITY: usize = 256;
+
+/// Frames the output of a VLC player's interface into responses, without doing any I/O itself.
+///
+/// Every command's response is framed by VLC's prompt: a response is made up of all the lines VLC writes after a command, up until the prompt appears at the start of a line. Only prompts at the start of a line count, so a `>` in a media title can't cut a response short.
+///
+/// Status change notifications are moved out of the responses and into a queue, so that they can't be mistaken for a command's output.
+///
+/// Responses that arrive after their command has given up waiting for them (e.g. after a read timeout) are discarded before the next command's response is taken, so that every command gets its own response.
+pub(crate) struct Framer {
+    /// Output that has been read from VLC, but not yet framed as a response.
+    pending: Vec<u8>,
+    /// The amount of responses VLC owes us, including the greeting.
+    outstanding: usize,
+    /// Status changes that have been received, but not yet drained.
+    status_changes: VecDeque<StatusChange>,
+    /// Whether or not a greeting nobody's waiting for may still arrive as the first response.
+    unexpected_greeting: bool,
+}
+
+impl Framer {
+    /// Creates a framer that isn't waiting on any responses yet.
+    pub fn new() -> Framer {
+        Self {
+            pending: Vec::new(),
+            outstanding: 0,
+            status_changes: VecDeque::new(),
+            unexpected_greeting: false,
+        }
+    }
+
+    /// Records that VLC owes us another response, either to a command that has been sent or as its greeting.
+    pub fn expect_response(&mut self) {
+        self.outstanding += 1;
+    }
+
+    /// Records that VLC may greet us without anyone waiting for it, in which case the greeting is skipped rather than taken as the first command's response.
+    pub fn skip_greeting(&mut self) {
+        self.unexpected_greeting = true;
+    }
+
+    /// Adds output that has been read from VLC.
+    pub fn feed(&mut self, out: &[u8]) {
+        self.pending.extend_from_slice(out);
+    }
+
+    /// Takes the response VLC owes us last, discarding the late responses before it.
+    ///
+    /// Returns `None` if more output has to be [fed](Framer::feed) first.
+    pub fn next_response(&mut self) -> Option<String> {
+        while self.outstanding > 0 {
+            let res = split_response(&mut self.pending, self.outstanding == 1)?;
+
+            // Only the very first response can be a greeting.
+            if std::mem::take(&mut self.unexpected_greeting)
+                && res.lines().any(|line| line.ends_with(GREETING_SUFFIX))
+            {
+                continue;
+            }
+            self.outstanding -= 1;
+
+            if self.outstanding == 0 {
+                return Some(res);
+            }
+            // Keep the notifications, even though nobody is waiting for the rest of the response anymore.
+            route_status_changes(&res, &mut self.status_changes, false);
+        }
+        None
+    }
+
+    /// Moves the status change notifications out of the given response, returning the rest of it.
+    pub fn route(&mut self, res: &str) -> String {
+        route_status_changes(res, &mut self.status_changes, true)
+    }
+
+    /// Removes and returns every status change received so far, oldest first.
+    pub fn status_changes(
+        &mut self,
+    ) -> impl Iterator<Item = StatusChange> + '_ {
+        self.status_changes.drain(..)
+    }
+}
+
+/// Returns [`Error::InvalidCommand`] if the given command contains a line break.
+pub(crate) fn check_command(cmd: &str) -> Result<()> {
+    if cmd.contains(['\n', '\r']) {
+        return Err(Error::InvalidCommand);
+    }
+    Ok(())
+}
+
+/// Splits the first complete response off of the given VLC output.
+///
+/// VLC writes a response straight after the prompt that ends the previous one, so output starting with a prompt is either an empty response, or a response whose first line starts with `> ` (e.g. a title like `> Intro.mp3`). When the response is the `last` one VLC owes us, nothing can follow it - so it's only taken to be empty if the prompt is all there is.
+///
+/// Returns `None` if the output doesn't contain a complete response yet.
+fn split_response(out: &mut Vec<u8>, last: bool) -> Option<String> {
+    let prompt = [PROMPT, b' '];
+
+    // The prompt only counts at the start of a line.
+    let end = if out.starts_with(&prompt) && (!last || out.len() == 2) {
+        0
+    } else {
+        out.windows(3).position(|w| w[0] == b'\n' && w[1..] == prompt)? + 1
+    };
+
+    let rest = out.split_off(end + prompt.len());
+    let res = std::mem::replace(out, rest);
+
+    Some(
+        String::from_utf8_lossy(&res[..end])
+            .lines()
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// Splits canned VLC output into its responses, each ending with its prompt, so that tests can hand them out one command at a time like VLC does.
+///
+/// Output starting with a prompt is always taken to be an empty response.
+#[cfg(test)]
+pub(crate) fn split_canned(mut out: &[u8]) -> VecDeque<Vec<u8>> {
+    let mut responses = VecDeque::new();
+    while !out.is_empty() {
+        let end = if out.starts_with(&[PROMPT, b' ']) {
+            2
+        } else {
+            out.windows(3)
+                .position(|w| w == [b'\n', PROMPT, b' '])
+                .map_or(out.len(), |i| i + 3)
+        };
+        responses.push_back(out[..end].to_vec());
+        out = &out[end..];
+    }
+    responses
+}
+
+/// Moves the status change notifications out of the given response and into the queue, returning the rest of the response.
+///
+/// Lines with VLC's `status change:` prefix are always moved, while bare status lines (e.g. `( audio volume: 256 )`) are only moved if `bare` is `true`.
+fn route_status_changes(
+    res: &str,
+    queue: &mut VecDeque<StatusChange>,
+    bare: bool,
+) -> String {
+    let mut lines = Vec::new();
+    for line in res.lines() {
+        let change = (bare || line.starts_with(STATUS_CHANGE_PREFIX))
+            .then(|| StatusChange::from_parts(line))
+            .flatten();
+
+        match change {
+            Some(change) => {
+                if queue.len() == STATUS_CHANGES_CAPACITY {
+                    queue.pop_front();
+                }
+                queue.push_back(change);
+            }
+            None => lines.push(line),
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::VecDeque;
+
+    use super::route_status_changes;
+    use super::split_response;
+    use super::Framer;
+    use super::StatusChange;
+
+    #[test]
+    fn framer_waits_for_prompt() {
+        let mut framer = Framer::new();
+        framer.expect_response();
+
+        framer.feed(b"4");
+        assert_eq!(framer.next_response(), None);
+        framer.feed(b"2\r\n> ");
+        assert_eq!(framer.next_response(), Some("42".into()));
+    }
+
+    #[test]
+    fn framer_discards_late_responses() {
+        let mut framer = Framer::new();
+        framer.expect_response();
+        framer.expect_response();
+
+        framer
+            .feed(b"status change: ( audio volume: 256 )\r\n256\r\n> 12\r\n> ");
+        assert_eq!(framer.next_response(), Some("12".into()));
+        assert_eq!(
+            framer.status_changes().collect::<Vec<_>>(),
+            [StatusChange::Volume(256)]
+        );
+    }
+
+    #[test]
+    fn framer_skips_unexpected_greeting() {
+        let mut framer = Framer::new();
+        framer.skip_greeting();
+        framer.expect_response();
+
+        framer.feed(b"VLC media player 3.0.16 Vetinari\r\nCommand Line Interface initialized. Type `help' for help.\r\n> ");
+        assert_eq!(framer.next_response(), None);
+        framer.feed(b"42\r\n> ");
+        assert_eq!(framer.next_response(), Some("42".into()));
+    }
+
+    #[test]
+    fn split_response_incomplete() {
+        let mut out = b"42\r\n".to_vec();
+        assert_eq!(split_response(&mut out, false), None);
+        assert_eq!(out, b"42\r\n");
+
+        let mut out =
+            b"+----[ Playlist - playlist ]\r\n| 1 - A > B.mp3".to_vec();
+        assert_eq!(split_response(&mut out, false), None);
+    }
+
+    #[test]
+    fn split_response_empty() {
+        let mut out = b"> > 42\r\n> ".to_vec();
+        assert_eq!(split_response(&mut out, false), Some("".into()));
+        assert_eq!(split_response(&mut out, false), Some("".into()));
+        assert_eq!(split_response(&mut out, false), Some("42".into()));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn split_response_prompt_in_title() {
+        let mut out =
+            b"|   4 - A > B.mp3 (00:00:30)\r\n|   5 - C | D >.mp3 (00:01:00)\r\n> A > B.mp3\r\n> "
+                .to_vec();
+        assert_eq!(
+            split_response(&mut out, false),
+            Some(
+                "|   4 - A > B.mp3 (00:00:30)\n|   5 - C | D >.mp3 (00:01:00)"
+                    .into()
+            )
+        );
+        assert_eq!(split_response(&mut out, false), Some("A > B.mp3".into()));
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn split_response_prompt_at_start_of_title() {
+        let mut out = b"> Intro.mp3\r\n> ".to_vec();
+        assert_eq!(split_response(&mut out, true), Some("> Intro.mp3".into()));
+        assert!(out.is_empty());
+
+        let mut out = b"> ".to_vec();
+        assert_eq!(split_response(&mut out, true), Some("".into()));
+    }
+
+    #[test]
+    fn route_status_changes_bare() {
+        let mut queue = VecDeque::new();
+        let res = route_status_changes(
+            "status change: ( audio volume: 256 )\n( state playing )\n1",
+            &mut queue,
+            true,
+        );
+
+        assert_eq!(res, "1");
+        assert_eq!(
+            queue,
+            [StatusChange::Volume(256), StatusChange::State("playing".into())]
+        );
+    }
+
+    #[test]
+    fn route_status_changes_prefixed() {
+        let mut queue = VecDeque::new();
+        let res = route_status_changes(
+            "( audio volume: 128 )\nstatus change: ( play state: 3 )\n( state paused )",
+            &mut queue,
+            false,
+        );
+
+        assert_eq!(res, "( audio volume: 128 )\n( state paused )");
+        assert_eq!(queue, [StatusChange::PlayState(3)]);
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use super::media::FromParts;
+
+/// The header of the section VLC lists the current input's tags in.
+const META_HEADER: &str = "+----[ Meta data ]";
+
+/// The kind of an elementary stream in the current input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StreamKind {
+    /// An audio stream.
+    Audio,
+    /// A video stream.
+    Video,
+    /// A subtitle stream.
+    Subtitle,
+    /// Any other kind of stream - holds the type VLC reports.
+    Other(String),
+}
+
+impl From<&str> for StreamKind {
+    fn from(kind: &str) -> Self {
+        match kind {
+            "Audio" => StreamKind::Audio,
+            "Video" => StreamKind::Video,
+            "Subtitle" => StreamKind::Subtitle,
+            other => StreamKind::Other(other.to_owned()),
+        }
+    }
+}
+
+/// An elementary stream in the current input, as reported by VLC's `info` command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamInfo {
+    index: u32,
+    kind: StreamKind,
+    fields: HashMap<String, String>,
+}
+
+impl StreamInfo {
+    /// Gets the stream's index in the input.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Gets the stream's kind.
+    pub fn kind(&self) -> &StreamKind {
+        &self.kind
+    }
+
+    /// Gets the stream's codec, e.g. `MPEG Audio layer 1/2/3 (mpga)`.
+    pub fn codec(&self) -> Option<&str> {
+        self.field("Codec")
+    }
+
+    /// Gets the stream's language.
+    pub fn language(&self) -> Option<&str> {
+        self.field("Language")
+    }
+
+    /// Gets the stream's bitrate (in kb/s).
+    pub fn bitrate(&self) -> Option<u32> {
+        self.field("Bitrate")?.strip_suffix(" kb/s")?.parse().ok()
+    }
+
+    /// Gets an audio stream's sample rate (in Hz).
+    pub fn sample_rate(&self) -> Option<u32> {
+        self.field("Sample rate")?.strip_suffix(" Hz")?.parse().ok()
+    }
+
+    /// Gets an audio stream's channel layout, e.g. `Stereo`.
+    pub fn channels(&self) -> Option<&str> {
+        self.field("Channels")
+    }
+
+    /// Gets a video stream's resolution as `(<width>, <height>)`.
+    pub fn resolution(&self) -> Option<(u32, u32)> {
+        let (width, height) =
+            self.field("Video resolution")?.split_once('x')?;
+        Some((width.parse().ok()?, height.parse().ok()?))
+    }
+
+    /// Gets a video stream's frame rate (in frames per second).
+    pub fn frame_rate(&self) -> Option<f32> {
+        self.field("Frame rate")?.parse().ok()
+    }
+
+    /// Gets any field VLC reports for the stream by its name, e.g. `Decoded format`.
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.get(name).map(String::as_str)
+    }
+}
+
+impl FromParts for StreamInfo {
+    fn from_parts(parts: &str) -> Option<Self> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?x)
+                ^
+                \+----\[\s
+                Stream\s(?P<index>\d+) # The stream's index.
+                \s\]
+        ",
+            )
+            .unwrap();
+        };
+
+        let (header, body) = parts.split_once('\n').unwrap_or((parts, ""));
+        let caps = REGEX.captures(header.trim())?;
+        let mut fields = parse_fields(body);
+
+        Some(Self {
+            index: caps["index"].parse().ok()?,
+            kind: StreamKind::from(fields.remove("Type")?.as_str()),
+            fields,
+        })
+    }
+}
+
+/// Information about the current input, as reported by VLC's `info` command.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MediaInfo {
+    meta: HashMap<String, String>,
+    streams: Vec<StreamInfo>,
+}
+
+impl MediaInfo {
+    /// Gets the input's tags (e.g. `title`, `artist` or `filename`) by their names.
+    pub fn meta(&self) -> &HashMap<String, String> {
+        &self.meta
+    }
+
+    /// Gets the input's elementary streams.
+    pub fn streams(&self) -> &[StreamInfo] {
+        &self.streams
+    }
+}
+
+impl FromParts for MediaInfo {
+    fn from_parts(parts: &str) -> Option<Self> {
+        let mut info = MediaInfo::default();
+        for section in split_sections(parts) {
+            if section.starts_with(META_HEADER) {
+                info.meta = parse_fields(section);
+            } else if let Some(stream) = StreamInfo::from_parts(section) {
+                info.streams.push(stream);
+            }
+        }
+        info.streams.sort_by_key(StreamInfo::index);
+
+        // VLC always lists the tags, so their absence means that this isn't its `info` output.
+        parts.contains(META_HEADER).then_some(info)
+    }
+}
+
+/// Splits VLC output into its `+----[ <name> ]` sections, each starting with its header line.
+fn split_sections(out: &str) -> Vec<&str> {
+    let mut starts: Vec<_> =
+        out.match_indices("+----[").map(|(i, _)| i).collect();
+    starts.push(out.len());
+
+    starts.windows(2).map(|w| out[w[0]..w[1]].trim_end()).collect()
+}
+
+/// Parses the `| <name>: <value>` lines of a section into a map, ignoring any padding around the colon.
+pub(crate) fn parse_fields(section: &str) -> HashMap<String, String> {
+    lazy_static! {
+        static ref REGEX: Regex = Regex::new(
+            r"(?x)
+            ^
+            \|\s* # List item delimiter.
+            (?P<name>[^:]+?) # The field's name.
+            \s*:\s*
+            (?P<value>.*?) # The field's value.
+            \s*
+            $
+        ",
+        )
+        .unwrap();
+    };
+
+    section
+        .lines()
+        .filter_map(|line| REGEX.captures(line))
+        .map(|caps| (caps["name"].to_owned(), caps["value"].to_owned()))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const INFO: &str = "+----[ Meta data ]
+|
+| title: Nocturne in E-flat major
+| artist: Chopin
+| filename: audio.mp3
+|
++----[ Stream 1 ]
+|
+| Type: Video
+| Codec: H264 - MPEG-4 AVC (part 10) (h264)
+| Video resolution: 1920x1080
+| Frame rate: 23.976024
+| Decoded format: Planar 4:2:0 YUV
+|
++----[ Stream 0 ]
+|
+| Type: Audio
+| Codec: MPEG Audio layer 1/2/3 (mpga)
+| Language: English
+| Channels: Stereo
+| Sample rate: 44100 Hz
+| Bitrate: 128 kb/s
+|
++----[ Stream 2 ]
+|
+| Type: Subtitle
+| Codec: Text subtitles with various tags (subt)
+| Language: French
+|
++----[ end of stream info ]";
+
+    #[test]
+    fn stream_info_from_parts_none() {
+        assert_eq!(StreamInfo::from_parts("+----[ Meta data ]\n|"), None);
+        assert_eq!(StreamInfo::from_parts("+----[ Stream 0 ]\n|\n|"), None);
+    }
+
+    #[test]
+    fn stream_info_from_parts_some() {
+        let stream = StreamInfo::from_parts(
+            "+----[ Stream 1 ]\n|\n| Type: Video\n| Video resolution: 1280x720\n| Frame rate: 25\n|",
+        )
+        .unwrap();
+
+        assert_eq!(stream.index(), 1);
+        assert_eq!(stream.kind(), &StreamKind::Video);
+        assert_eq!(stream.resolution(), Some((1280, 720)));
+        assert_eq!(stream.frame_rate(), Some(25.0));
+        assert_eq!(stream.codec(), None);
+    }
+
+    #[test]
+    fn media_info_from_parts_none() {
+        assert_eq!(MediaInfo::from_parts(""), None);
+        assert_eq!(MediaInfo::from_parts("+----[ end of stream info ]"), None);
+    }
+
+    #[test]
+    fn media_info_from_parts_some() {
+        let info = MediaInfo::from_parts(INFO).unwrap();
+
+        assert_eq!(info.meta().len(), 3);
+        assert_eq!(info.meta()["artist"], "Chopin");
+
+        let streams = info.streams();
+        assert_eq!(streams.len(), 3);
+
+        assert_eq!(streams[0].kind(), &StreamKind::Audio);
+        assert_eq!(streams[0].codec(), Some("MPEG Audio layer 1/2/3 (mpga)"));
+        assert_eq!(streams[0].language(), Some("English"));
+        assert_eq!(streams[0].channels(), Some("Stereo"));
+        assert_eq!(streams[0].sample_rate(), Some(44100));
+        assert_eq!(streams[0].bitrate(), Some(128));
+
+        assert_eq!(streams[1].kind(), &StreamKind::Video);
+        assert_eq!(streams[1].resolution(), Some((1920, 1080)));
+        assert_eq!(streams[1].frame_rate(), Some(23.976024));
+        assert_eq!(
+            streams[1].field("Decoded format"),
+            Some("Planar 4:2:0 YUV")
+        );
+
+        assert_eq!(streams[2].kind(), &StreamKind::Subtitle);
+        assert_eq!(streams[2].language(), Some("French"));
+    }
+}
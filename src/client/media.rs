@@ -7,24 +7,55 @@ pub const MIN_VOLUME: u8 = 0;
 /// The maximum amount for a volume setting.
 pub const MAX_VOLUME: u8 = 200;
 
+/// The slowest playback rate VLC supports.
+pub const MIN_RATE: f32 = 0.03125;
+
+/// The fastest playback rate VLC supports - it stores rates as the integer `1000 / rate`, which can't go below 32.
+pub const MAX_RATE: f32 = 31.25;
+
 /// A type alias for a collection of [tracks](Track).
 pub type Playlist = Vec<Track>;
 
 /// A type alias for a collection of [subtitles](Subtitle).
 pub type Subtitles = Vec<Subtitle>;
 
+/// A type alias for a collection of [audio tracks](AudioTrack).
+pub type AudioTracks = Vec<AudioTrack>;
+
+/// A type alias for a collection of [video tracks](VideoTrack).
+pub type VideoTracks = Vec<VideoTrack>;
+
+/// A type alias for a collection of [audio devices](AudioDevice).
+pub type AudioDevices = Vec<AudioDevice>;
+
+/// A type alias for a collection of [audio channel modes](AudioChannel).
+pub type AudioChannels = Vec<AudioChannel>;
+
 /// A trait implemented by types that can be constructed from the VLC interface's output.
 pub(crate) trait FromParts: Sized {
     /// Attempts to construct a type from the given VLC output - returning `None` if it is not possible.
     fn from_parts(parts: &str) -> Option<Self>;
 }
 
+/// A trait implemented by the items of VLC's listings that only have one item selected at a time, such as audio tracks.
+pub(crate) trait Selectable {
+    /// The type of the value VLC identifies the item by.
+    type Key: PartialEq + std::fmt::Display;
+
+    /// Gets the value VLC identifies the item by.
+    fn key(&self) -> Self::Key;
+
+    /// Returns whether or not the item is the selected one.
+    fn is_active(&self) -> bool;
+}
+
 /// A media track in a VLC player's [playlist](Playlist).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Track {
     index: i32,
     title: String,
-    length: String,
+    length: Option<String>,
+    current: bool,
 }
 
 impl Track {
@@ -39,14 +70,25 @@ impl Track {
     }
 
     /// Gets the track's length as `<hours>:<minutes>:<seconds>`.
-    pub fn length(&self) -> &str {
-        &self.length
+    ///
+    /// Returns `None` if VLC doesn't know it, e.g. for streams or items that haven't been parsed yet.
+    pub fn length(&self) -> Option<&str> {
+        self.length.as_deref()
+    }
+
+    /// Returns whether or not the track is the playlist's current track - VLC marks it with a `*`.
+    pub fn is_current(&self) -> bool {
+        self.current
     }
 }
 
 impl std::fmt::Display for Track {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} - {} ({})", self.index, self.title, self.length)
+        write!(f, "{} - {}", self.index, self.title)?;
+        if let Some(length) = &self.length {
+            write!(f, " ({})", length)?;
+        }
+        Ok(())
     }
 }
 
@@ -55,68 +97,377 @@ impl FromParts for Track {
         lazy_static! {
             static ref REGEX: Regex = Regex::new(
                 r"(?x)
-                \| # List item delimiter.
-                \s+
-                [\*]?
+                ^
+                \|\s # List item delimiter.
+                (?P<indent>\s*) # The item's indentation - grows with its depth in the playlist tree.
+                (?P<current>[\*])? # The current track's marker.
                 (?P<index>[\d]+) # The track's index.
                 \s+
                 -
                 \s+
-                (?P<title>.+) # The track's title.
-                \s
-                \(
-                (?P<length>\d\d:\d\d:\d\d) # The track's length.
-                .*
+                (?P<title>.+?) # The track's title.
+                (?:\s\((?P<length>\d\d:\d\d:\d\d)\))? # The track's length, if VLC knows it.
+                (?:\s\[played\s\d+\stimes?\])? # How often the track has been played.
+                \s*
+                $
         ",
             )
             .unwrap();
         };
         let caps = REGEX.captures(parts)?;
+
+        // The root nodes (`Playlist` and `Media Library`) aren't indented and never have a length.
+        let length = caps.name("length").map(|m| m.as_str().to_owned());
+        if length.is_none() && caps["indent"].is_empty() {
+            return None;
+        }
+
         Some(Self {
             index: caps["index"].parse().ok()?,
             title: caps["title"].to_owned(),
-            length: caps["length"].to_owned(),
+            length,
+            current: caps.name("current").is_some(),
+        })
+    }
+}
+
+/// A key that VLC can sort its playlist by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SortKey {
+    /// Sorts by the order in which the tracks were added.
+    Id,
+    /// Sorts by title.
+    Title,
+    /// Sorts by artist.
+    Artist,
+    /// Sorts by genre.
+    Genre,
+    /// Sorts by album.
+    Album,
+    /// Sorts by length.
+    Duration,
+    /// Shuffles the playlist.
+    Random,
+}
+
+impl std::fmt::Display for SortKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let key = match self {
+            SortKey::Id => "id",
+            SortKey::Title => "title",
+            SortKey::Artist => "artist",
+            SortKey::Genre => "genre",
+            SortKey::Album => "album",
+            SortKey::Duration => "duration",
+            SortKey::Random => "random",
+        };
+        f.write_str(key)
+    }
+}
+
+/// The order to sort a playlist in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SortOrder {
+    /// From the lowest to the highest value.
+    #[default]
+    Ascending,
+    /// From the highest to the lowest value.
+    Descending,
+}
+
+/// The playlist's playback modes, as last set through a [`Client`](crate::Client).
+///
+/// VLC 3 doesn't report its playback modes, so they can't be read back from the player. Instead, the client keeps track of the modes it sets - a mode that hasn't been set through the client is unknown, and may have been changed by someone else since.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PlaybackModes {
+    pub(crate) repeat: Option<bool>,
+    pub(crate) looping: Option<bool>,
+    pub(crate) random: Option<bool>,
+}
+
+impl PlaybackModes {
+    /// Returns whether or not the current track is repeated when it ends, if the mode has been set.
+    pub fn repeat(&self) -> Option<bool> {
+        self.repeat
+    }
+
+    /// Returns whether or not the playlist starts over when it ends, if the mode has been set.
+    pub fn looping(&self) -> Option<bool> {
+        self.looping
+    }
+
+    /// Returns whether or not the playlist is played in a random order, if the mode has been set.
+    pub fn random(&self) -> Option<bool> {
+        self.random
+    }
+}
+
+/// Whether the player is playing, paused or stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlaybackState {
+    /// The current track is playing.
+    Playing,
+    /// The current track is paused.
+    Paused,
+    /// Nothing is playing.
+    Stopped,
+}
+
+impl PlaybackState {
+    /// VLC's play state code for a playing input.
+    const PLAYING_CODE: u8 = 2;
+
+    /// VLC's play state code for a paused input.
+    const PAUSED_CODE: u8 = 3;
+}
+
+impl FromParts for PlaybackState {
+    fn from_parts(parts: &str) -> Option<Self> {
+        match StatusChange::from_parts(parts)? {
+            StatusChange::PlayState(Self::PLAYING_CODE) => Some(Self::Playing),
+            StatusChange::PlayState(Self::PAUSED_CODE) => Some(Self::Paused),
+            // The remaining codes belong to inputs that are opening, ending or failing.
+            StatusChange::PlayState(_) => Some(Self::Stopped),
+            StatusChange::State(state) => match state.as_str() {
+                "playing" => Some(Self::Playing),
+                "paused" => Some(Self::Paused),
+                "stopped" => Some(Self::Stopped),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+/// A title or chapter of the current input, such as a DVD's menu or one of a movie's scenes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Section {
+    number: u32,
+    count: Option<u32>,
+}
+
+impl Section {
+    /// Gets the section's number - VLC counts them from 0.
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Gets how many sections of its kind the current input has, if VLC reports it.
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+}
+
+impl std::fmt::Display for Section {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.count {
+            Some(count) => write!(f, "{}/{}", self.number, count),
+            None => write!(f, "{}", self.number),
+        }
+    }
+}
+
+impl FromParts for Section {
+    fn from_parts(parts: &str) -> Option<Self> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?x)
+                ^
+                (?:Currently\ playing\ (?:title|chapter)\s+)? # The prefix of VLC's verbose report.
+                (?P<number>\d+) # The current section's number.
+                (?:/(?P<count>\d+))? # The amount of sections.
+                \.?
+                \s*
+                $
+        ",
+            )
+            .unwrap();
+        };
+
+        let caps = REGEX.captures(parts.trim())?;
+        Some(Self {
+            number: caps["number"].parse().ok()?,
+            count: match caps.name("count") {
+                Some(count) => Some(count.as_str().parse().ok()?),
+                None => None,
+            },
         })
     }
 }
 
-/// A subtitle track associated with a media file.
+/// Parses an item of VLC's elementary stream listings (e.g. `atrack`) into its index, title and whether or not it's the active one.
+fn parse_es(parts: &str) -> Option<(i32, String, bool)> {
+    lazy_static! {
+        static ref REGEX: Regex = Regex::new(
+            r"(?x)
+            \| # List item delimiter.
+            \s+
+            (?P<index>[\-]?[\d]+) # The track's index.
+            \s+
+            -
+            \s+
+            (?P<title>.+?) # The track's title.
+            (?:\s(?P<active>\*))? # The active track's marker.
+            \s*
+            $
+        ",
+        )
+        .unwrap();
+    };
+
+    let caps = REGEX.captures(parts)?;
+    Some((
+        caps["index"].parse().ok()?,
+        caps["title"].to_owned(),
+        caps.name("active").is_some(),
+    ))
+}
+
+/// Defines an item of VLC's elementary stream listings, parsed with [`parse_es`], along with its getters and trait implementations.
+macro_rules! es_item {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            index: $index_doc:literal,
+            title: $title_doc:literal,
+            active: $active_doc:literal $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub struct $name {
+            index: i32,
+            title: String,
+            active: bool,
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{} - {}", self.index, self.title)
+            }
+        }
+
+        impl $name {
+            #[doc = $index_doc]
+            pub fn index(&self) -> i32 {
+                self.index
+            }
+
+            #[doc = $title_doc]
+            pub fn title(&self) -> &str {
+                &self.title
+            }
+
+            #[doc = $active_doc]
+            pub fn is_active(&self) -> bool {
+                self.active
+            }
+        }
+
+        impl FromParts for $name {
+            fn from_parts(parts: &str) -> Option<Self> {
+                let (index, title, active) = parse_es(parts)?;
+                Some(Self { index, title, active })
+            }
+        }
+
+        impl Selectable for $name {
+            type Key = i32;
+
+            fn key(&self) -> i32 {
+                self.index
+            }
+
+            fn is_active(&self) -> bool {
+                self.active
+            }
+        }
+    };
+}
+
+es_item! {
+    /// A subtitle track associated with a media file.
+    Subtitle {
+        index: "Gets the subtitle track's index in VLC - the `-1` track disables subtitles.",
+        title: "Gets the subtitle track's name.",
+        active: "Returns whether or not the subtitle track is the one being shown - VLC marks it with a `*`.",
+    }
+}
+
+es_item! {
+    /// An audio track associated with a media file.
+    AudioTrack {
+        index: "Gets the audio track's index in VLC - the `-1` track disables audio.",
+        title: "Gets the audio track's name.",
+        active: "Returns whether or not the audio track is the one being played - VLC marks it with a `*`.",
+    }
+}
+
+es_item! {
+    /// A video track associated with a media file.
+    VideoTrack {
+        index: "Gets the video track's index in VLC - the `-1` track disables video.",
+        title: "Gets the video track's name.",
+        active: "Returns whether or not the video track is the one being played - VLC marks it with a `*`.",
+    }
+}
+
+es_item! {
+    /// An audio channel mode, such as stereo, reverse stereo or mono.
+    AudioChannel {
+        index: "Gets the channel mode's index in VLC.",
+        title: "Gets the channel mode's name, e.g. `Stereo`.",
+        active: "Returns whether or not the channel mode is the one in use - VLC marks it with a `*`.",
+    }
+}
+
+/// An audio output device VLC can play through.
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Subtitle {
-    index: i32,
+pub struct AudioDevice {
+    id: String,
     title: String,
+    active: bool,
 }
 
-impl std::fmt::Display for Subtitle {
+impl std::fmt::Display for AudioDevice {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} - {}", self.index, self.title)
+        write!(f, "{} - {}", self.id, self.title)
     }
 }
 
-impl Subtitle {
-    /// Gets the subtitle track's index in VLC.
-    pub fn index(&self) -> i32 {
-        self.index
+impl AudioDevice {
+    /// Gets the identifier VLC's audio output uses for the device - the default device's identifier is empty.
+    pub fn id(&self) -> &str {
+        &self.id
     }
 
-    /// Gets the subtitle track's name.
+    /// Gets the device's name.
     pub fn title(&self) -> &str {
         &self.title
     }
+
+    /// Returns whether or not the device is the one being played through - VLC marks it with a `*`.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
 }
 
-impl FromParts for Subtitle {
+impl FromParts for AudioDevice {
     fn from_parts(parts: &str) -> Option<Self> {
         lazy_static! {
             static ref REGEX: Regex = Regex::new(
                 r"(?x)
+                ^
                 \| # List item delimiter.
-                \s+
-                (?P<index>[\-]?[\d]+) # The subtitle's index.
-                \s+
+                \s
+                (?P<id>\S*) # The device's identifier.
+                \s
                 -
-                \s+
-                (?P<title>.+) # The subtitle track's title.
+                \s
+                (?P<title>.+?) # The device's name.
+                (?:\s(?P<active>\*))? # The active device's marker.
+                \s*
+                $
         ",
             )
             .unwrap();
@@ -124,12 +475,93 @@ impl FromParts for Subtitle {
 
         let caps = REGEX.captures(parts)?;
         Some(Self {
-            index: caps["index"].parse().ok()?,
+            id: caps["id"].to_owned(),
             title: caps["title"].to_owned(),
+            active: caps.name("active").is_some(),
         })
     }
 }
 
+impl Selectable for AudioDevice {
+    type Key = String;
+
+    fn key(&self) -> String {
+        self.id.clone()
+    }
+
+    fn is_active(&self) -> bool {
+        self.active
+    }
+}
+
+/// An unsolicited notification VLC interleaves with command output when the player's state changes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StatusChange {
+    /// A new input has been opened - holds the input's URI.
+    NewInput(String),
+    /// The audio volume has changed - holds VLC's raw volume.
+    Volume(u16),
+    /// The input's play state has changed - holds VLC's play state code.
+    PlayState(u8),
+    /// The playlist's state has changed - holds the state's name (e.g. `playing`, `paused` or `stopped`).
+    State(String),
+}
+
+impl std::fmt::Display for StatusChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StatusChange::NewInput(uri) => write!(f, "( new input: {} )", uri),
+            StatusChange::Volume(volume) => {
+                write!(f, "( audio volume: {} )", volume)
+            }
+            StatusChange::PlayState(code) => {
+                write!(f, "( play state: {} )", code)
+            }
+            StatusChange::State(state) => write!(f, "( state {} )", state),
+        }
+    }
+}
+
+impl FromParts for StatusChange {
+    fn from_parts(parts: &str) -> Option<Self> {
+        lazy_static! {
+            static ref REGEX: Regex = Regex::new(
+                r"(?x)
+                ^
+                (?:status\ change:\s*)? # The prefix of unsolicited notifications.
+                \(
+                \s*
+                (?:
+                    new\ input:\s*(?P<input>\S.*?) # The new input's URI.
+                    |
+                    audio\ volume:\s*(?P<volume>\d+) # The new volume.
+                    |
+                    play\ state:\s*(?P<play>\d+) # The new play state code.
+                    |
+                    state\s+(?P<state>\w+) # The new state's name.
+                )
+                \s*
+                \)
+                \s*
+                $
+        ",
+            )
+            .unwrap();
+        };
+
+        let caps = REGEX.captures(parts)?;
+        if let Some(input) = caps.name("input") {
+            Some(Self::NewInput(input.as_str().to_owned()))
+        } else if let Some(volume) = caps.name("volume") {
+            Some(Self::Volume(volume.as_str().parse().ok()?))
+        } else if let Some(play) = caps.name("play") {
+            Some(Self::PlayState(play.as_str().parse().ok()?))
+        } else {
+            Some(Self::State(caps["state"].to_owned()))
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -160,7 +592,8 @@ mod test {
             Some(Track {
                 index: 8,
                 title: "Chopin Nocturnes.mp3".into(),
-                length: "01:50:55".into()
+                length: Some("01:50:55".into()),
+                current: false,
             })
         );
         test_from_parts!(
@@ -168,11 +601,67 @@ mod test {
             Some(Track {
                 index: 1,
                 title: "Bach (00:00:01).mp3".into(),
-                length: "01:50:55".into()
+                length: Some("01:50:55".into()),
+                current: true,
+            })
+        );
+        test_from_parts!(
+            "|   5 - audio.mp3 (00:00:30) [played 2 times]",
+            Some(Track {
+                index: 5,
+                title: "audio.mp3".into(),
+                length: Some("00:00:30".into()),
+                current: false,
+            })
+        );
+        test_from_parts!(
+            "|   6 - https://example.com/stream.mp3",
+            Some(Track {
+                index: 6,
+                title: "https://example.com/stream.mp3".into(),
+                length: None,
+                current: false,
             })
         );
     }
 
+    #[test]
+    fn playback_state_from_parts_none() {
+        test_from_parts!(PlaybackState, "( audio volume: 256 )", None);
+        test_from_parts!(PlaybackState, "( state unknown )", None);
+    }
+
+    #[test]
+    fn playback_state_from_parts_some() {
+        test_from_parts!("( state playing )", Some(PlaybackState::Playing));
+        test_from_parts!("( state stopped )", Some(PlaybackState::Stopped));
+        test_from_parts!(
+            "status change: ( play state: 3 )",
+            Some(PlaybackState::Paused)
+        );
+        test_from_parts!("( play state: 4 )", Some(PlaybackState::Stopped));
+    }
+
+    #[test]
+    fn section_from_parts_none() {
+        test_from_parts!(Section, "", None);
+        test_from_parts!(Section, "-1", None);
+        test_from_parts!(Section, "Currently playing title", None);
+    }
+
+    #[test]
+    fn section_from_parts_some() {
+        test_from_parts!(
+            "Currently playing title 1/3.",
+            Some(Section { number: 1, count: Some(3) })
+        );
+        test_from_parts!(
+            "Currently playing chapter 12/24.",
+            Some(Section { number: 12, count: Some(24) })
+        );
+        test_from_parts!("2", Some(Section { number: 2, count: None }));
+    }
+
     #[test]
     fn subtitle_from_parts_none() {
         test_from_parts!(Subtitle, "+----[ spu-es ]", None);
@@ -183,11 +672,147 @@ mod test {
     fn subtitle_from_parts_some() {
         test_from_parts!(
             "| -1 - Disable *",
-            Some(Subtitle { index: -1, title: "Disable *".into() })
+            Some(Subtitle { index: -1, title: "Disable".into(), active: true })
         );
         test_from_parts!(
             "| 2 - Track 1 - [English]",
-            Some(Subtitle { index: 2, title: "Track 1 - [English]".into() })
+            Some(Subtitle {
+                index: 2,
+                title: "Track 1 - [English]".into(),
+                active: false
+            })
+        );
+    }
+
+    #[test]
+    fn audio_track_from_parts_none() {
+        test_from_parts!(AudioTrack, "+----[ audio-es ]", None);
+        test_from_parts!(AudioTrack, "+----[ end of audio-es ]", None);
+    }
+
+    #[test]
+    fn audio_track_from_parts_some() {
+        test_from_parts!(
+            "| -1 - Disable",
+            Some(AudioTrack {
+                index: -1,
+                title: "Disable".into(),
+                active: false
+            })
+        );
+        test_from_parts!(
+            "| 1 - Track 1 - [English] *",
+            Some(AudioTrack {
+                index: 1,
+                title: "Track 1 - [English]".into(),
+                active: true
+            })
+        );
+    }
+
+    #[test]
+    fn video_track_from_parts_none() {
+        test_from_parts!(VideoTrack, "+----[ video-es ]", None);
+        test_from_parts!(VideoTrack, "+----[ end of video-es ]", None);
+    }
+
+    #[test]
+    fn video_track_from_parts_some() {
+        test_from_parts!(
+            "| -1 - Disable *",
+            Some(VideoTrack {
+                index: -1,
+                title: "Disable".into(),
+                active: true
+            })
+        );
+        test_from_parts!(
+            "| 0 - Track 1 - [Angle 2]",
+            Some(VideoTrack {
+                index: 0,
+                title: "Track 1 - [Angle 2]".into(),
+                active: false
+            })
+        );
+    }
+
+    #[test]
+    fn audio_device_from_parts_none() {
+        test_from_parts!(AudioDevice, "+----[ adev ]", None);
+        test_from_parts!(AudioDevice, "+----[ end of adev ]", None);
+    }
+
+    #[test]
+    fn audio_device_from_parts_some() {
+        test_from_parts!(
+            "|  - Default *",
+            Some(AudioDevice {
+                id: "".into(),
+                title: "Default".into(),
+                active: true
+            })
+        );
+        test_from_parts!(
+            "| alsa_output.pci-0000_00_1f.3.analog-stereo - Built-in Audio Analog Stereo",
+            Some(AudioDevice {
+                id: "alsa_output.pci-0000_00_1f.3.analog-stereo".into(),
+                title: "Built-in Audio Analog Stereo".into(),
+                active: false
+            })
+        );
+    }
+
+    #[test]
+    fn audio_channel_from_parts_none() {
+        test_from_parts!(AudioChannel, "+----[ achan ]", None);
+        test_from_parts!(AudioChannel, "+----[ end of achan ]", None);
+    }
+
+    #[test]
+    fn audio_channel_from_parts_some() {
+        test_from_parts!(
+            "| 1 - Stereo *",
+            Some(AudioChannel {
+                index: 1,
+                title: "Stereo".into(),
+                active: true
+            })
+        );
+        test_from_parts!(
+            "| 2 - Reverse stereo",
+            Some(AudioChannel {
+                index: 2,
+                title: "Reverse stereo".into(),
+                active: false
+            })
+        );
+    }
+
+    #[test]
+    fn status_change_from_parts_none() {
+        test_from_parts!(StatusChange, "256", None);
+        test_from_parts!(StatusChange, "| 1 - Playlist", None);
+        test_from_parts!(StatusChange, "( audio volume: loud )", None);
+        test_from_parts!(StatusChange, "( new input:  )", None);
+    }
+
+    #[test]
+    fn status_change_from_parts_some() {
+        test_from_parts!(
+            "status change: ( audio volume: 256 )",
+            Some(StatusChange::Volume(256))
+        );
+        test_from_parts!(
+            "( new input: file:///home/user/A (1).mp3 )",
+            Some(StatusChange::NewInput("file:///home/user/A (1).mp3".into()))
+        );
+        test_from_parts!(
+            "status change: ( play state: 3 )",
+            Some(StatusChange::PlayState(3))
+        );
+        test_from_parts!(
+            "( state playing )",
+            Some(StatusChange::State("playing".into()))
         );
     }
 }
@@ -0,0 +1,91 @@
+use std::ffi::OsStr;
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// A builder for the command line used to launch a VLC player that the client owns.
+///
+/// The player is always started with VLC's `rc` interface reading from stdin (`-I rc --rc-fake-tty`), followed by any extra arguments and then the media to open.
+///
+/// # Examples
+///
+/// ```
+/// use vlc_rc::client::VlcCommand;
+///
+/// let cmd = VlcCommand::new()
+///     .program("/usr/bin/vlc")
+///     .arg("--no-video")
+///     .media("samples/audio.mp3");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VlcCommand {
+    program: PathBuf,
+    args: Vec<OsString>,
+    media: Vec<OsString>,
+}
+
+impl VlcCommand {
+    /// The VLC binary that is launched when no other program is given.
+    pub const DEFAULT_PROGRAM: &'static str = "vlc";
+
+    /// Creates a new command that launches the [default](Self::DEFAULT_PROGRAM) VLC binary without any media.
+    pub fn new() -> VlcCommand {
+        Self {
+            program: PathBuf::from(Self::DEFAULT_PROGRAM),
+            args: Vec::new(),
+            media: Vec::new(),
+        }
+    }
+
+    /// Sets the path to the VLC binary.
+    pub fn program<P>(mut self, path: P) -> VlcCommand
+    where
+        P: Into<PathBuf>,
+    {
+        self.program = path.into();
+        self
+    }
+
+    /// Adds an extra argument to pass to VLC.
+    pub fn arg<S>(mut self, arg: S) -> VlcCommand
+    where
+        S: AsRef<OsStr>,
+    {
+        self.args.push(arg.as_ref().to_owned());
+        self
+    }
+
+    /// Adds multiple extra arguments to pass to VLC.
+    pub fn args<I, S>(mut self, args: I) -> VlcCommand
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<OsStr>,
+    {
+        self.args.extend(args.into_iter().map(|a| a.as_ref().to_owned()));
+        self
+    }
+
+    /// Adds a file path or URI to VLC's initial playlist.
+    pub fn media<S>(mut self, media: S) -> VlcCommand
+    where
+        S: AsRef<OsStr>,
+    {
+        self.media.push(media.as_ref().to_owned());
+        self
+    }
+
+    /// Builds the [`Command`] used to spawn the VLC process.
+    pub(crate) fn to_command(&self) -> Command {
+        let mut cmd = Command::new(&self.program);
+        cmd.args(["-I", "rc", "--rc-fake-tty"])
+            .args(&self.args)
+            .args(&self.media);
+        cmd
+    }
+}
+
+impl Default for VlcCommand {
+    fn default() -> Self {
+        Self::new()
+    }
+}
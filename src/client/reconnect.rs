@@ -0,0 +1,188 @@
+use std::io::ErrorKind;
+use std::net::ToSocketAddrs;
+use std::thread;
+use std::time::Duration;
+
+use crate::Error;
+use crate::Result;
+
+use super::Client;
+use super::ClientBuilder;
+
+/// The delays used between attempts to reconnect to VLC.
+///
+/// The delay starts at `initial` and doubles after every failed attempt, up to `max`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Backoff {
+    /// The delay before the first attempt to reconnect.
+    pub initial: Duration,
+    /// The longest delay between two attempts.
+    pub max: Duration,
+    /// The amount of attempts made before giving up.
+    pub attempts: u32,
+}
+
+impl Default for Backoff {
+    fn default() -> Self {
+        Self {
+            initial: Duration::from_millis(100),
+            max: Duration::from_secs(5),
+            attempts: 5,
+        }
+    }
+}
+
+/// A [`Client`] that transparently reconnects to VLC when its connection breaks.
+///
+/// Commands are issued through [`ReconnectingClient::run`]. If a command fails because VLC has closed the connection (e.g. it was restarted), the client reconnects, optionally restores the last known volume and fullscreen mode, and then retries the command once.
+///
+/// # Examples
+///
+/// ```
+/// use vlc_rc::client::ReconnectingClient;
+///
+/// let mut player = ReconnectingClient::connect("127.0.0.1:9090").unwrap();
+///
+/// player.run(|client| client.set_volume(50)).unwrap();
+/// let title = player.run(|client| client.get_title()).unwrap();
+/// ```
+pub struct ReconnectingClient {
+    client: Client,
+    connect: Box<dyn FnMut() -> Result<Client> + Send>,
+    backoff: Backoff,
+    restore: bool,
+}
+
+impl ReconnectingClient {
+    /// Creates a reconnecting client that uses the given function to (re)connect to VLC.
+    ///
+    /// The function is called once immediately, and again every time the connection breaks.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// use vlc_rc::client::ClientBuilder;
+    /// use vlc_rc::client::ReconnectingClient;
+    ///
+    /// let builder = ClientBuilder::new().read_timeout(Some(Duration::from_secs(5)));
+    ///
+    /// let player = ReconnectingClient::new(move || builder.connect("127.0.0.1:9090")).unwrap();
+    /// ```
+    pub fn new<F>(mut connect: F) -> Result<ReconnectingClient>
+    where
+        F: FnMut() -> Result<Client> + Send + 'static,
+    {
+        Ok(Self {
+            client: connect()?,
+            connect: Box::new(connect),
+            backoff: Backoff::default(),
+            restore: true,
+        })
+    }
+
+    /// Establishes a reconnecting connection to a VLC player's TCP interface at the given address.
+    pub fn connect<A>(addr: A) -> Result<ReconnectingClient>
+    where
+        A: ToSocketAddrs + Send + 'static,
+    {
+        let builder = ClientBuilder::new();
+        Self::new(move || builder.connect(&addr))
+    }
+
+    /// Sets the delays used between attempts to reconnect.
+    pub fn backoff(mut self, backoff: Backoff) -> ReconnectingClient {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sets whether or not the last known volume and fullscreen mode are re-applied after reconnecting.
+    ///
+    /// Enabled by default.
+    pub fn restore_state(mut self, restore: bool) -> ReconnectingClient {
+        self.restore = restore;
+        self
+    }
+
+    /// Issues commands with the underlying client, reconnecting and retrying once if VLC closed the connection.
+    ///
+    /// Any other error is returned as-is.
+    pub fn run<T, F>(&mut self, mut f: F) -> Result<T>
+    where
+        F: FnMut(&mut Client) -> Result<T>,
+    {
+        match f(&mut self.client) {
+            Err(e) if is_disconnect(&e) => {
+                self.reconnect()?;
+                f(&mut self.client)
+            }
+            res => res,
+        }
+    }
+
+    /// Replaces the underlying client with a fresh connection to VLC.
+    ///
+    /// Returns the last error if every attempt fails.
+    pub fn reconnect(&mut self) -> Result<()> {
+        let mut delay = self.backoff.initial;
+        let mut last_err = None;
+
+        for _ in 0..self.backoff.attempts {
+            thread::sleep(delay);
+            delay = (delay * 2).min(self.backoff.max);
+
+            match self.try_reconnect() {
+                Ok(()) => return Ok(()),
+                Err(e) => last_err = Some(e),
+            }
+        }
+        Err(last_err.unwrap_or_else(|| {
+            Error::Io(std::io::ErrorKind::NotConnected.into())
+        }))
+    }
+
+    /// Gets a reference to the underlying client.
+    pub fn get_ref(&self) -> &Client {
+        &self.client
+    }
+
+    /// Gets a mutable reference to the underlying client.
+    ///
+    /// Commands issued directly through the client are not retried.
+    pub fn get_mut(&mut self) -> &mut Client {
+        &mut self.client
+    }
+
+    /// Makes a single attempt to connect to VLC and restore the previous client's state.
+    fn try_reconnect(&mut self) -> Result<()> {
+        let mut client = (self.connect)()?;
+
+        if self.restore {
+            if let Some(volume) = self.client.volume {
+                client.set_volume(volume)?;
+            }
+            if let Some(on) = self.client.fullscreen {
+                client.fullscreen(on)?;
+            }
+        }
+
+        self.client = client;
+        Ok(())
+    }
+}
+
+/// Returns whether or not the error means that the connection to VLC is broken.
+fn is_disconnect(e: &Error) -> bool {
+    match e {
+        Error::Io(e) => matches!(
+            e.kind(),
+            ErrorKind::BrokenPipe
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::ConnectionReset
+                | ErrorKind::NotConnected
+                | ErrorKind::UnexpectedEof
+        ),
+        _ => false,
+    }
+}
@@ -4,65 +4,212 @@ use std::io::BufWriter;
 
 use std::net::Shutdown;
 use std::net::TcpStream;
-use std::net::ToSocketAddrs;
 
-use std::time::Duration;
+#[cfg(unix)]
+use std::os::unix::net::UnixStream;
 
+use std::process::Child;
+use std::process::Command;
+use std::process::Stdio;
+
+use crate::Error;
 use crate::Result;
 
-/// The byte used to prompt a client for a command.
-pub const PROMPT: u8 = b'>';
+use super::framing::check_command;
+use super::framing::Framer;
+use super::media::StatusChange;
 
-/// A wrapper around a [`TcpStream`] that enables buffered I/O calls.
-pub struct IoSocket {
-    reader: BufReader<TcpStream>,
-    writer: BufWriter<TcpStream>,
-}
+/// The text VLC's telnet interface uses to prompt a client for its password.
+const PASSWORD_PROMPT: &[u8] = b"Password: ";
 
-impl IoSocket {
-    /// The default maximum amount of time that can pass before a read call is terminated.
-    const READ_TIMEOUT: Duration = Duration::from_secs(1);
+/// The byte that starts a telnet command sequence ("Interpret As Command").
+const IAC: u8 = 0xff;
 
-    /// The default maximum amount of time that can pass before a write call is terminated.
-    const WRITE_TIMEOUT: Duration = Duration::from_secs(1);
+/// A byte stream connected to a VLC player's interface.
+enum Stream {
+    /// A connection to VLC's TCP interface (`--rc-host`).
+    Tcp(TcpStream),
+    /// A connection to VLC's Unix domain socket interface (`--rc-unix`).
+    #[cfg(unix)]
+    Unix(UnixStream),
+}
 
-    /// Establishes a connection to the VLC player's TCP interface at the given address.
-    pub fn connect<A>(addr: A) -> Result<IoSocket>
-    where
-        A: ToSocketAddrs,
-    {
-        let stream = TcpStream::connect(addr)?;
+impl Stream {
+    /// Creates a new independently owned handle to the underlying stream.
+    fn try_clone(&self) -> std::io::Result<Stream> {
+        match self {
+            Stream::Tcp(s) => s.try_clone().map(Stream::Tcp),
+            #[cfg(unix)]
+            Stream::Unix(s) => s.try_clone().map(Stream::Unix),
+        }
+    }
 
-        stream.set_read_timeout(Some(Self::READ_TIMEOUT))?;
-        stream.set_write_timeout(Some(Self::WRITE_TIMEOUT))?;
+    /// Shuts down the read, write, or both halves of the underlying stream.
+    fn shutdown(&self, how: Shutdown) -> std::io::Result<()> {
+        match self {
+            Stream::Tcp(s) => s.shutdown(how),
+            #[cfg(unix)]
+            Stream::Unix(s) => s.shutdown(how),
+        }
+    }
+}
 
-        let mut reader = BufReader::new(stream.try_clone()?);
-        {
-            // Consume the greeting VLC gives a client when it connects.
-            let mut greeting = Vec::new();
-            reader.read_until(PROMPT, &mut greeting)?;
+impl Read for Stream {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Stream::Tcp(s) => s.read(buf),
+            #[cfg(unix)]
+            Stream::Unix(s) => s.read(buf),
         }
+    }
+}
 
-        let writer = BufWriter::new(stream);
+impl Write for Stream {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Stream::Tcp(s) => s.write(buf),
+            #[cfg(unix)]
+            Stream::Unix(s) => s.write(buf),
+        }
+    }
 
-        Ok(Self { reader, writer })
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Stream::Tcp(s) => s.flush(),
+            #[cfg(unix)]
+            Stream::Unix(s) => s.flush(),
+        }
     }
+}
 
-    /// Closes the underling [`TcpStream`]'s connection.
-    pub fn shutdown(&self) -> Result<()> {
-        self.reader.get_ref().shutdown(Shutdown::Read)?;
-        self.writer.get_ref().shutdown(Shutdown::Write)?;
+/// A byte stream that carries VLC's `rc` protocol.
+///
+/// Implement this trait to drive a [`Client`](crate::Client) over streams the library doesn't provide itself, such as TLS tunnels, SSH channels or in-memory pipes.
+///
+/// # Examples
+///
+/// ```
+/// use std::io::prelude::*;
+/// use std::io::Cursor;
+///
+/// use vlc_rc::client::Transport;
+///
+/// /// Replays canned VLC output and records every command sent to it.
+/// struct Replay {
+///     output: Cursor<Vec<u8>>,
+///     commands: Vec<u8>,
+/// }
+///
+/// impl Read for Replay {
+///     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+///         self.output.read(buf)
+///     }
+/// }
+///
+/// impl BufRead for Replay {
+///     fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+///         self.output.fill_buf()
+///     }
+///
+///     fn consume(&mut self, amt: usize) {
+///         self.output.consume(amt)
+///     }
+/// }
+///
+/// impl Write for Replay {
+///     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+///         self.commands.write(buf)
+///     }
+///
+///     fn flush(&mut self) -> std::io::Result<()> {
+///         Ok(())
+///     }
+/// }
+///
+/// impl Transport for Replay {}
+/// ```
+pub trait Transport: BufRead + Write + Send {
+    /// Closes the stream.
+    ///
+    /// Called when the [`Client`](crate::Client) using the transport is dropped. Does nothing by default.
+    fn shutdown(&mut self) -> Result<()> {
         Ok(())
     }
 }
 
-impl Read for IoSocket {
+/// The connection or process on the other end of a [`BufStream`].
+enum Peer {
+    /// A VLC player listening on a socket.
+    Stream(Stream),
+    /// A VLC player spawned and owned by the client, driven over its stdin/stdout.
+    Child(Child),
+}
+
+/// A buffered [`Transport`] over one of the connections the library supports out of the box.
+pub struct BufStream {
+    reader: BufReader<Box<dyn Read + Send>>,
+    writer: BufWriter<Box<dyn Write + Send>>,
+    peer: Peer,
+}
+
+impl BufStream {
+    /// Sets up buffered I/O over a connection to VLC's TCP interface.
+    pub fn tcp(stream: TcpStream) -> Result<BufStream> {
+        Self::from_stream(Stream::Tcp(stream))
+    }
+
+    /// Sets up buffered I/O over a connection to VLC's Unix domain socket interface.
+    #[cfg(unix)]
+    pub fn unix(stream: UnixStream) -> Result<BufStream> {
+        Self::from_stream(Stream::Unix(stream))
+    }
+
+    /// Spawns the given VLC command and communicates with it over its stdin/stdout.
+    ///
+    /// Pipes do not support timeouts, so reads block until the child process writes to its stdout.
+    pub fn spawn(cmd: &mut Command) -> Result<BufStream> {
+        let mut child = cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        // Both handles are present since we've requested them to be piped.
+        let stdin = child.stdin.take().expect("the child's stdin is piped");
+        let stdout = child.stdout.take().expect("the child's stdout is piped");
+
+        Ok(Self::new(Box::new(stdout), Box::new(stdin), Peer::Child(child)))
+    }
+
+    /// Sets up buffered I/O over the given stream.
+    fn from_stream(stream: Stream) -> Result<BufStream> {
+        let reader = Box::new(stream.try_clone()?);
+        let writer = Box::new(stream.try_clone()?);
+
+        Ok(Self::new(reader, writer, Peer::Stream(stream)))
+    }
+
+    /// Wraps the given reader and writer in buffers.
+    fn new(
+        reader: Box<dyn Read + Send>,
+        writer: Box<dyn Write + Send>,
+        peer: Peer,
+    ) -> BufStream {
+        Self {
+            reader: BufReader::new(reader),
+            writer: BufWriter::new(writer),
+            peer,
+        }
+    }
+}
+
+impl Read for BufStream {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         self.reader.read(buf)
     }
 }
 
-impl BufRead for IoSocket {
+impl BufRead for BufStream {
     fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
         self.reader.fill_buf()
     }
@@ -70,18 +217,9 @@ impl BufRead for IoSocket {
     fn consume(&mut self, amt: usize) {
         self.reader.consume(amt)
     }
-
-    fn read_line(&mut self, buf: &mut String) -> std::io::Result<usize> {
-        let amt = self.reader.read_line(buf)?;
-
-        // The prompt can appear as an "artifact" when `read_line` is called repeatedly, so we need to trim the buffer's output to counter this.
-        trim_output(buf);
-
-        Ok(amt)
-    }
 }
 
-impl Write for IoSocket {
+impl Write for BufStream {
     fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
         self.writer.write(buf)
     }
@@ -91,10 +229,251 @@ impl Write for IoSocket {
     }
 }
 
-/// Removes any instances of the [`PROMPT`] along with any whitespace characters from the start of the string.
-fn trim_output(buf: &mut String) {
-    (buf.starts_with(PROMPT as char) || buf.starts_with(' ')).then(|| {
-        buf.remove(0);
-        trim_output(buf);
-    });
+impl Transport for BufStream {
+    /// Closes the connection to VLC.
+    ///
+    /// If the VLC player was spawned by the client, its process is killed and reaped.
+    fn shutdown(&mut self) -> Result<()> {
+        match self.peer {
+            Peer::Stream(ref stream) => stream.shutdown(Shutdown::Both)?,
+            Peer::Child(ref mut child) => {
+                // The process may have already exited on its own, in which case there is nothing to kill.
+                let _ = child.kill();
+                child.wait()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A session with a VLC player's interface over any [`Transport`].
+///
+/// The transport's output is framed into responses by a [`Framer`].
+pub struct IoSocket {
+    transport: Box<dyn Transport>,
+    framer: Framer,
+}
+
+impl IoSocket {
+    /// Starts a session over the given transport.
+    pub fn new<T>(transport: T) -> IoSocket
+    where
+        T: Transport + 'static,
+    {
+        Self { transport: Box::new(transport), framer: Framer::new() }
+    }
+
+    /// Consumes the greeting VLC gives a client when it connects.
+    ///
+    /// The transport is shut down if the greeting can't be read.
+    pub fn consume_greeting(&mut self) -> Result<()> {
+        self.framer.expect_response();
+        if let Err(e) = self.next_response() {
+            // Don't leave a spawned process behind if it never greeted us.
+            let _ = self.shutdown();
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    /// Skips VLC's greeting if it arrives, instead of waiting for it.
+    pub fn skip_greeting(&mut self) {
+        self.framer.skip_greeting();
+    }
+
+    /// Logs in to VLC's telnet interface with the given password.
+    ///
+    /// Returns [`Error::Auth`] and shuts the transport down if VLC rejects the password.
+    pub fn login(&mut self, password: &str) -> Result<()> {
+        self.read_telnet_until(&[PASSWORD_PROMPT])?;
+
+        writeln!(self.transport, "{}", password)?;
+        self.transport.flush()?;
+
+        // VLC either welcomes us with a command prompt, or asks for the password again.
+        let out = self.read_telnet_until(&[b"> ", PASSWORD_PROMPT])?;
+        if out.ends_with(PASSWORD_PROMPT) {
+            let _ = self.shutdown();
+            return Err(Error::Auth);
+        }
+        Ok(())
+    }
+
+    /// Reads telnet output until it ends with one of the given patterns, discarding any telnet command sequences along the way.
+    fn read_telnet_until(&mut self, patterns: &[&[u8]]) -> Result<Vec<u8>> {
+        let mut out = Vec::new();
+        // The amount of bytes left in the current telnet command sequence.
+        let mut skip = 0;
+
+        while !patterns.iter().any(|p| out.ends_with(p)) {
+            let mut byte = [0];
+            if self.transport.read(&mut byte)? == 0 {
+                return Err(std::io::Error::from(
+                    std::io::ErrorKind::UnexpectedEof,
+                )
+                .into());
+            }
+
+            match byte[0] {
+                _ if skip > 0 => skip -= 1,
+                // VLC only negotiates options (e.g. `IAC WILL ECHO`), which are always 3 bytes long.
+                IAC => skip = 2,
+                b => out.push(b),
+            }
+        }
+        Ok(out)
+    }
+
+    /// Sends a command to VLC and reads its response.
+    ///
+    /// The response's lines are separated by `\n`, and an empty string is returned for commands that don't output anything.
+    ///
+    /// Returns [`Error::InvalidCommand`] if the command contains a line break.
+    pub fn execute(&mut self, cmd: &str) -> Result<String> {
+        self.send(cmd)?;
+        let res = self.next_response()?;
+
+        Ok(self.framer.route(&res))
+    }
+
+    /// Asks VLC to report the player's status, and returns the report as is.
+    ///
+    /// Unlike [`IoSocket::execute`], the status lines are kept in the response rather than moved into the queue, since they're what the caller asked for.
+    pub fn status(&mut self) -> Result<String> {
+        self.send("status")?;
+        self.next_response()
+    }
+
+    /// Removes and returns every status change received so far, oldest first.
+    pub fn status_changes(
+        &mut self,
+    ) -> impl Iterator<Item = StatusChange> + '_ {
+        self.framer.status_changes()
+    }
+
+    /// Writes the given command to VLC.
+    fn send(&mut self, cmd: &str) -> Result<()> {
+        check_command(cmd)?;
+
+        writeln!(self.transport, "{}", cmd)?;
+        self.transport.flush()?;
+        self.framer.expect_response();
+        Ok(())
+    }
+
+    /// Reads the response to the last command sent, discarding the late responses to any earlier ones.
+    fn next_response(&mut self) -> Result<String> {
+        loop {
+            if let Some(res) = self.framer.next_response() {
+                return Ok(res);
+            }
+
+            let buf = self.transport.fill_buf()?;
+            if buf.is_empty() {
+                // VLC always ends a response with a prompt, so the stream ending before one means that the connection has been closed.
+                return Err(std::io::Error::from(
+                    std::io::ErrorKind::UnexpectedEof,
+                )
+                .into());
+            }
+
+            let amt = buf.len();
+            self.framer.feed(buf);
+            self.transport.consume(amt);
+        }
+    }
+
+    /// Closes the underlying transport.
+    pub fn shutdown(&mut self) -> Result<()> {
+        self.transport.shutdown()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::VecDeque;
+    use std::io::prelude::*;
+    use std::io::ErrorKind;
+
+    use crate::Error;
+    use crate::Result;
+
+    use super::IoSocket;
+    use super::Transport;
+
+    /// A transport that hands out the given chunks of VLC output one read at a time, timing out on every `None`.
+    struct Chunks(VecDeque<Option<&'static [u8]>>);
+
+    impl Read for Chunks {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            let amt = self.fill_buf()?.read(buf)?;
+            self.consume(amt);
+            Ok(amt)
+        }
+    }
+
+    impl BufRead for Chunks {
+        fn fill_buf(&mut self) -> std::io::Result<&[u8]> {
+            match self.0.front() {
+                Some(None) => {
+                    self.0.pop_front();
+                    Err(ErrorKind::TimedOut.into())
+                }
+                Some(Some(chunk)) => Ok(chunk),
+                None => Ok(&[]),
+            }
+        }
+
+        fn consume(&mut self, amt: usize) {
+            if let Some(Some(chunk)) = self.0.front_mut() {
+                *chunk = &chunk[amt..];
+                if chunk.is_empty() {
+                    self.0.pop_front();
+                }
+            }
+        }
+    }
+
+    impl Write for Chunks {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Transport for Chunks {}
+
+    #[test]
+    fn late_response_is_discarded() -> Result<()> {
+        let mut socket = IoSocket::new(Chunks(VecDeque::from([
+            None,
+            Some(&b"256\r\n> "[..]),
+            Some(&b"12\r\n> "[..]),
+        ])));
+
+        assert!(matches!(
+            socket.execute("volume"),
+            Err(Error::Io(e)) if e.kind() == ErrorKind::TimedOut
+        ));
+        assert_eq!(socket.execute("get_time")?, "12");
+
+        Ok(())
+    }
+
+    #[test]
+    fn command_with_line_break() {
+        let mut socket = IoSocket::new(Chunks(VecDeque::new()));
+
+        assert!(matches!(
+            socket.execute("search a\nshutdown"),
+            Err(Error::InvalidCommand)
+        ));
+        assert!(matches!(
+            socket.execute("add a\r"),
+            Err(Error::InvalidCommand)
+        ));
+    }
 }
@@ -0,0 +1,239 @@
+use super::info::parse_fields;
+use super::media::FromParts;
+
+/// The header VLC starts its `stats` output with - VLC 3 doesn't close its brackets, so only its start is matched.
+const STATS_HEADER: &str = "+----[ begin of statistical info";
+
+/// Playback statistics for the current input, as reported by VLC's `stats` command.
+///
+/// VLC only reports the streaming statistics if it's built with streaming output, so they're left at `0` otherwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct PlaybackStats {
+    input_read: u64,
+    input_bitrate: u64,
+    demux_read: u64,
+    demux_bitrate: u64,
+    demux_corrupted: u64,
+    discontinuities: u64,
+    video_decoded: u64,
+    frames_displayed: u64,
+    frames_lost: u64,
+    audio_decoded: u64,
+    buffers_played: u64,
+    buffers_lost: u64,
+    packets_sent: u64,
+    sent: u64,
+    send_bitrate: u64,
+}
+
+impl PlaybackStats {
+    /// Gets the amount of data read from the input (in KiB).
+    pub fn input_read(&self) -> u64 {
+        self.input_read
+    }
+
+    /// Gets the input's bitrate (in kb/s).
+    pub fn input_bitrate(&self) -> u64 {
+        self.input_bitrate
+    }
+
+    /// Gets the amount of data read by the demuxer (in KiB).
+    pub fn demux_read(&self) -> u64 {
+        self.demux_read
+    }
+
+    /// Gets the demuxer's bitrate (in kb/s).
+    pub fn demux_bitrate(&self) -> u64 {
+        self.demux_bitrate
+    }
+
+    /// Gets the amount of corrupted packets the demuxer has found.
+    pub fn demux_corrupted(&self) -> u64 {
+        self.demux_corrupted
+    }
+
+    /// Gets the amount of discontinuities the demuxer has found.
+    pub fn discontinuities(&self) -> u64 {
+        self.discontinuities
+    }
+
+    /// Gets the amount of video frames decoded.
+    pub fn video_decoded(&self) -> u64 {
+        self.video_decoded
+    }
+
+    /// Gets the amount of video frames displayed.
+    pub fn frames_displayed(&self) -> u64 {
+        self.frames_displayed
+    }
+
+    /// Gets the amount of video frames dropped.
+    pub fn frames_lost(&self) -> u64 {
+        self.frames_lost
+    }
+
+    /// Gets the amount of audio blocks decoded.
+    pub fn audio_decoded(&self) -> u64 {
+        self.audio_decoded
+    }
+
+    /// Gets the amount of audio buffers played.
+    pub fn buffers_played(&self) -> u64 {
+        self.buffers_played
+    }
+
+    /// Gets the amount of audio buffers dropped.
+    pub fn buffers_lost(&self) -> u64 {
+        self.buffers_lost
+    }
+
+    /// Gets the amount of packets streamed.
+    pub fn packets_sent(&self) -> u64 {
+        self.packets_sent
+    }
+
+    /// Gets the amount of data streamed (in KiB).
+    pub fn sent(&self) -> u64 {
+        self.sent
+    }
+
+    /// Gets the streaming bitrate (in kb/s).
+    pub fn send_bitrate(&self) -> u64 {
+        self.send_bitrate
+    }
+}
+
+impl FromParts for PlaybackStats {
+    fn from_parts(parts: &str) -> Option<Self> {
+        if !parts.trim_start().starts_with(STATS_HEADER) {
+            return None;
+        }
+
+        let mut stats = PlaybackStats::default();
+        for (name, value) in parse_fields(parts) {
+            // Values are followed by their unit (e.g. `1234 KiB`), if they have one. A field whose value doesn't parse is skipped, rather than discarding the rest of the report.
+            let value = match value
+                .split_whitespace()
+                .next()
+                .and_then(|value| value.parse().ok())
+            {
+                Some(value) => value,
+                None => continue,
+            };
+            let field = match name.as_str() {
+                "input bytes read" => &mut stats.input_read,
+                "input bitrate" => &mut stats.input_bitrate,
+                "demux bytes read" => &mut stats.demux_read,
+                "demux bitrate" => &mut stats.demux_bitrate,
+                "demux corrupted" => &mut stats.demux_corrupted,
+                "discontinuities" => &mut stats.discontinuities,
+                "video decoded" => &mut stats.video_decoded,
+                "frames displayed" => &mut stats.frames_displayed,
+                "frames lost" => &mut stats.frames_lost,
+                "audio decoded" => &mut stats.audio_decoded,
+                "buffers played" => &mut stats.buffers_played,
+                "buffers lost" => &mut stats.buffers_lost,
+                "packets sent" => &mut stats.packets_sent,
+                "bytes sent" => &mut stats.sent,
+                "sending bitrate" => &mut stats.send_bitrate,
+                _ => continue,
+            };
+            *field = value;
+        }
+        Some(stats)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const STATS: &str = "+----[ begin of statistical info
++-[Incoming]
+| input bytes read :     1536 KiB
+| input bitrate    :      128 kb/s
+| demux bytes read :     1500 KiB
+| demux bitrate    :      126 kb/s
+| demux corrupted  :        1
+| discontinuities  :        2
+|
++-[Video Decoding]
+| video decoded    :     2400
+| frames displayed :     2390
+| frames lost      :       10
+|
++-[Audio Decoding]
+| audio decoded    :     4096
+| buffers played   :     4090
+| buffers lost     :        6
+|
++-[Streaming]
+| packets sent     :        0
+| bytes sent       :        0 KiB
+| sending bitrate  :        0 kb/s
+|
++----[ end of statistical info ]";
+
+    #[test]
+    fn playback_stats_from_parts_none() {
+        assert_eq!(PlaybackStats::from_parts(""), None);
+        assert_eq!(PlaybackStats::from_parts("| frames lost : 10"), None);
+    }
+
+    #[test]
+    fn playback_stats_from_parts_some() {
+        assert_eq!(
+            PlaybackStats::from_parts(STATS),
+            Some(PlaybackStats {
+                input_read: 1536,
+                input_bitrate: 128,
+                demux_read: 1500,
+                demux_bitrate: 126,
+                demux_corrupted: 1,
+                discontinuities: 2,
+                video_decoded: 2400,
+                frames_displayed: 2390,
+                frames_lost: 10,
+                audio_decoded: 4096,
+                buffers_played: 4090,
+                buffers_lost: 6,
+                packets_sent: 0,
+                sent: 0,
+                send_bitrate: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn playback_stats_with_closed_header() {
+        let stats = STATS.replacen(
+            "begin of statistical info",
+            "begin of statistical info ]",
+            1,
+        );
+
+        assert_eq!(
+            PlaybackStats::from_parts(&stats),
+            PlaybackStats::from_parts(STATS)
+        );
+    }
+
+    #[test]
+    fn playback_stats_without_streaming() {
+        let (stats, _) = STATS.split_once("+-[Streaming]").unwrap();
+
+        let stats = PlaybackStats::from_parts(stats).unwrap();
+        assert_eq!(stats.frames_lost(), 10);
+        assert_eq!(stats.packets_sent(), 0);
+    }
+
+    #[test]
+    fn playback_stats_skips_unparsable_field() {
+        let stats = STATS.replace("2390", "many");
+
+        let stats = PlaybackStats::from_parts(&stats).unwrap();
+        assert_eq!(stats.frames_displayed(), 0);
+        assert_eq!(stats.frames_lost(), 10);
+        assert_eq!(stats.buffers_played(), 4090);
+    }
+}
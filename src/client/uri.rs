@@ -0,0 +1,82 @@
+use std::ffi::OsStr;
+use std::path::Path;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::Result;
+
+/// Converts the given media to a URI VLC can open.
+///
+/// Media that already is a URI (e.g. `https://...` or `file:///...`) is returned as-is, while anything else is treated as a filesystem path and turned into an absolute `file://` URI.
+pub(crate) fn to_uri(media: &OsStr) -> Result<String> {
+    lazy_static! {
+        static ref SCHEME: Regex =
+            Regex::new(r"^[a-zA-Z][a-zA-Z0-9+.\-]*://").unwrap();
+    };
+
+    if let Some(uri) = media.to_str().filter(|m| SCHEME.is_match(m)) {
+        return Ok(uri.to_owned());
+    }
+
+    let path = std::path::absolute(Path::new(media))?;
+    Ok(path_to_uri(&path))
+}
+
+/// Turns an absolute path into a `file://` URI, percent-encoding every byte that isn't allowed in a URI's path.
+fn path_to_uri(path: &Path) -> String {
+    #[cfg(unix)]
+    let bytes = {
+        use std::os::unix::ffi::OsStrExt;
+        path.as_os_str().as_bytes().to_vec()
+    };
+    #[cfg(not(unix))]
+    let bytes = path.to_string_lossy().replace('\\', "/").into_bytes();
+
+    let mut uri = String::from("file://");
+    // Windows paths start with a drive letter rather than a slash.
+    if !bytes.starts_with(b"/") {
+        uri.push('/');
+    }
+
+    for b in bytes {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' => uri.push(b as char),
+            b'-' | b'.' | b'_' | b'~' | b'/' | b':' => uri.push(b as char),
+            _ => uri.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    uri
+}
+
+#[cfg(test)]
+mod test {
+    use std::ffi::OsStr;
+    use std::path::Path;
+
+    use super::path_to_uri;
+    use super::to_uri;
+
+    #[test]
+    fn uri_unchanged() {
+        for uri in [
+            "file:///home/user/A%20B.mp3",
+            "https://example.com/stream.mp3",
+            "dvd:///dev/sr0",
+        ] {
+            assert_eq!(to_uri(OsStr::new(uri)).unwrap(), uri);
+        }
+    }
+
+    #[test]
+    fn path_to_file_uri() {
+        assert_eq!(
+            path_to_uri(Path::new("/home/user/Bach & Chopin [live] 100%.mp3")),
+            "file:///home/user/Bach%20%26%20Chopin%20%5Blive%5D%20100%25.mp3"
+        );
+        assert_eq!(
+            path_to_uri(Path::new("/música/Ünïcode.flac")),
+            "file:///m%C3%BAsica/%C3%9Cn%C3%AFcode.flac"
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use std::collections::VecDeque;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::Result;
+
+use super::Client;
+use super::Playlist;
+
+/// A change in a VLC player's state, as observed by a [`Watcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayerEvent {
+    /// The current track has changed - holds the new track's title, or `None` if there is no current track.
+    TrackChanged(Option<String>),
+    /// The volume has changed - holds the new volume.
+    VolumeChanged(u8),
+    /// Playback has started.
+    Started,
+    /// Playback has stopped.
+    Stopped,
+    /// Playback jumped to a different position in the current track - holds the new position (in seconds).
+    Seeked(u32),
+    /// The playlist's contents have changed - holds the new playlist.
+    PlaylistChanged(Playlist),
+}
+
+/// The state of a VLC player at a point in time.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Snapshot {
+    title: Option<String>,
+    time: Option<u32>,
+    volume: u8,
+    playing: bool,
+    playlist: Playlist,
+    taken_at: Instant,
+}
+
+impl Snapshot {
+    /// Captures the player's current state.
+    fn take(client: &mut Client) -> Result<Snapshot> {
+        Ok(Self {
+            title: client.get_title()?,
+            time: client.get_time()?,
+            volume: client.get_volume()?,
+            playing: client.is_playing()?,
+            playlist: client.playlist()?,
+            taken_at: Instant::now(),
+        })
+    }
+
+    /// Gets the events that lead from the previous snapshot to this one.
+    fn events_since(&self, prev: &Snapshot) -> Vec<PlayerEvent> {
+        let mut events = Vec::new();
+
+        if self.title != prev.title {
+            events.push(PlayerEvent::TrackChanged(self.title.clone()));
+        } else if let (Some(before), Some(after)) = (prev.time, self.time) {
+            // While playing, the position is expected to move along with the clock.
+            let expected = if prev.playing && self.playing {
+                let elapsed = self.taken_at.duration_since(prev.taken_at);
+                before + elapsed.as_secs() as u32
+            } else {
+                before
+            };
+            if after.abs_diff(expected) > Watcher::SEEK_TOLERANCE {
+                events.push(PlayerEvent::Seeked(after));
+            }
+        }
+
+        if self.volume != prev.volume {
+            events.push(PlayerEvent::VolumeChanged(self.volume));
+        }
+
+        match (prev.playing, self.playing) {
+            (false, true) => events.push(PlayerEvent::Started),
+            (true, false) => events.push(PlayerEvent::Stopped),
+            _ => {}
+        }
+
+        if self.playlist != prev.playlist {
+            events.push(PlayerEvent::PlaylistChanged(self.playlist.clone()));
+        }
+
+        events
+    }
+}
+
+/// An iterator that polls a VLC player on an interval and yields a [`PlayerEvent`] whenever its state changes.
+///
+/// The first poll only records the player's state, so no events are yielded for the state the player was already in. The iterator never ends on its own - errors are yielded as they occur, and polling continues afterwards.
+///
+/// Created by [`Client::watch`].
+pub struct Watcher<'a> {
+    client: &'a mut Client,
+    interval: Duration,
+    last: Option<Snapshot>,
+    events: VecDeque<PlayerEvent>,
+}
+
+impl<'a> Watcher<'a> {
+    /// The amount of seconds the playback position may drift from the expected position before it's treated as a seek.
+    const SEEK_TOLERANCE: u32 = 2;
+
+    /// Creates a watcher that polls the given client on the given interval.
+    pub(crate) fn new(client: &'a mut Client, interval: Duration) -> Self {
+        Self { client, interval, last: None, events: VecDeque::new() }
+    }
+
+    /// Polls the player once, queueing any events since the last poll.
+    fn poll(&mut self) -> Result<()> {
+        if let Some(ref last) = self.last {
+            thread::sleep(
+                self.interval.saturating_sub(last.taken_at.elapsed()),
+            );
+        }
+
+        let snapshot = Snapshot::take(self.client)?;
+        if let Some(ref last) = self.last {
+            self.events.extend(snapshot.events_since(last));
+        }
+        self.last = Some(snapshot);
+
+        Ok(())
+    }
+}
+
+impl Iterator for Watcher<'_> {
+    type Item = Result<PlayerEvent>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.events.pop_front() {
+                return Some(Ok(event));
+            }
+            if let Err(e) = self.poll() {
+                return Some(Err(e));
+            }
+        }
+    }
+}
@@ -11,6 +11,16 @@ pub enum Error {
     Io(IoError),
     /// The client failed to parse output received from VLC.
     ParseErr,
+    /// VLC's telnet interface rejected the client's password.
+    Auth,
+    /// VLC didn't apply a command that the client has verified.
+    CommandIgnored,
+    /// A command contains a line break or lacks its argument, which VLC would read as a different command.
+    InvalidCommand,
+    /// A value passed to a command is outside of the range VLC supports.
+    OutOfRange,
+    /// The current input doesn't have the requested kind of item (e.g. `"titles"` or `"chapters"`).
+    Unavailable(&'static str),
 }
 
 impl std::fmt::Display for Error {
@@ -21,6 +31,20 @@ impl std::fmt::Display for Error {
                 f,
                 "the client failed to parse the output received from VLC"
             ),
+            Error::Auth => write!(f, "VLC rejected the telnet password"),
+            Error::CommandIgnored => write!(f, "VLC ignored the command"),
+            Error::InvalidCommand => {
+                write!(
+                    f,
+                    "the command contains a line break or lacks its argument"
+                )
+            }
+            Error::OutOfRange => {
+                write!(f, "the value is outside of VLC's supported range")
+            }
+            Error::Unavailable(what) => {
+                write!(f, "the current input has no {}", what)
+            }
         }
     }
 }
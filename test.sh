@@ -22,15 +22,96 @@ cargo t --doc;
 # Run the parsing tests.
 test "track_from_parts_none";
 test "track_from_parts_some";
+test "playback_state_from_parts_none";
+test "playback_state_from_parts_some";
+test "section_from_parts_none";
+test "section_from_parts_some";
 test "subtitle_from_parts_none";
 test "subtitle_from_parts_some";
+test "stream_info_from_parts_none";
+test "stream_info_from_parts_some";
+test "media_info_from_parts_none";
+test "media_info_from_parts_some";
+test "playback_stats_from_parts_none";
+test "playback_stats_from_parts_some";
+test "playback_stats_with_closed_header";
+test "playback_stats_without_streaming";
+test "playback_stats_skips_unparsable_field";
+test "framer_waits_for_prompt";
+test "framer_discards_late_responses";
+test "framer_skips_unexpected_greeting";
+test "split_response_incomplete";
+test "split_response_empty";
+test "split_response_prompt_in_title";
+test "split_response_prompt_at_start_of_title";
+test "route_status_changes_bare";
+test "route_status_changes_prefixed";
+test "late_response_is_discarded";
+test "command_with_line_break";
+test "audio_track_from_parts_none";
+test "audio_track_from_parts_some";
+test "video_track_from_parts_none";
+test "video_track_from_parts_some";
+test "audio_device_from_parts_none";
+test "audio_device_from_parts_some";
+test "audio_channel_from_parts_none";
+test "audio_channel_from_parts_some";
+test "status_change_from_parts_none";
+test "status_change_from_parts_some";
+test "uri_unchanged";
+test "path_to_file_uri";
 
 # Run the client tests.
+test "from_transport";
+test "builder_without_greeting";
+test "builder_without_greeting_skips_greeting";
+test "playlist_with_prompt_in_title";
+test "title_starting_with_prompt";
+test "enqueue_returns_new_track";
+test "enqueue_stream_without_length";
+test "goto_verifies_current_track";
+test "goto_stream_without_length";
+test "delete_stream_without_length";
+test "move_item_verifies_position";
+test "sort_descending";
+test "clear_leaves_media_library";
+test "sort_invalid_key";
+test "set_loop_is_idempotent";
+test "pause_is_idempotent";
+test "state_without_input_is_stopped";
+test "chapter_n_returns_next_chapter";
+test "title_without_titles";
+test "set_rate_verifies_rate";
+test "set_rate_accepts_quantised_rate";
+test "faster_waits_for_rate_change";
+test "slower_at_slowest_rate";
+test "set_rate_out_of_range";
+test "frame_step_n_pauses_first";
+test "position_is_fraction_of_length";
+test "set_audio_track_verifies_active_track";
+test "set_video_track_disables_video";
+test "set_subtitle_and_disable";
+test "set_audio_device_verifies_active_device";
+test "set_audio_device_empty_id";
+test "set_audio_channel_unknown_mode";
+test "status_changes_are_queued";
+test "watch_changes";
+test "telnet_login";
+test "telnet_wrong_password";
+test "reconnect_after_eof";
 test "get_and_set_volume";
 test "play_and_stop";
 test "seek"
 test "forward";
 test "rewind";
 
+# Run the async client tests.
+cargo t --features tokio async_from_stream;
+cargo t --features tokio async_pause_is_idempotent;
+cargo t --features tokio async_read_timeout;
+cargo t --features tokio async_get_and_set_volume;
+cargo t --features tokio async_play_and_stop;
+cargo t --features tokio async_pause_and_resume;
+
 # Kill the VLC background process.
 trap "trap - SIGTERM && kill -- -$$" SIGINT SIGTERM EXIT